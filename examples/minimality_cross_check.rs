@@ -0,0 +1,34 @@
+use std::env;
+use temporal_graph::{MinimizationConfig, cross_check_minimality_file};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() != 2 {
+        eprintln!("Usage: {} <temporal_graphs.txt>", args[0]);
+        std::process::exit(1);
+    }
+
+    let config = MinimizationConfig::new().with_max_iterations(1000);
+
+    match cross_check_minimality_file(&args[1], &config) {
+        Ok(check) => {
+            println!("\nChecked {} graphs", check.graphs_checked);
+            println!("Undetermined: {}", check.undetermined.len());
+            println!("Disagreements: {}", check.disagreements.len());
+
+            for disagreement in &check.disagreements {
+                println!("\nGraph {}:", disagreement.index + 1);
+                println!("  Heuristic: {:?}", disagreement.verdict);
+                println!(
+                    "  Removable labels: {:?}",
+                    disagreement.oracle.removable_labels
+                );
+            }
+        }
+        Err(e) => {
+            eprintln!("\n✗ Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
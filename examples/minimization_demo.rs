@@ -1,8 +1,8 @@
-use temporal_graph::{TemporalGraph, MinimizationConfig};
+use temporal_graph::{MinimizationConfig, TemporalGraph};
 
 fn main() {
     let mut graph = TemporalGraph::new();
-    
+
     // Build example graph with potential redundancy
     graph.add_edge(0, 1, 7);
     graph.add_edge(1, 2, 1);
@@ -11,20 +11,20 @@ fn main() {
     graph.add_edge(2, 3, 5);
     graph.add_edge(0, 3, 3);
     graph.add_edge(0, 3, 6);
-    
+
     println!("Testing label minimization...\n");
-    
+
     let config = MinimizationConfig::new()
         .with_max_iterations(1000)
         .with_statistics()
         .verbose();
-    
+
     let result = graph.is_label_minimal_with_config(config);
-    
+
     println!("FINAL RESULT");
-    println!("Is minimal: {}", result.is_minimal);
+    println!("Verdict: {:?}", result.verdict);
     println!("Termination reason: {:?}", result.termination_reason);
-    
+
     if let Some(stats) = result.stats {
         println!("\nStatistics:");
         println!("  Iterations: {}", stats.iterations);
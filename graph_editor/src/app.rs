@@ -145,16 +145,16 @@ impl eframe::App for GraphEditorApp {
                     egui::Sense::click(),
                 );
 
-                if response.clicked() {
-                    if let Some(pos) = response.interact_pointer_pos() {
-                        self.on_click(pos);
-                    }
+                if response.clicked()
+                    && let Some(pos) = response.interact_pointer_pos()
+                {
+                    self.on_click(pos);
                 }
 
-                if response.secondary_clicked() {
-                    if let Some(pos) = response.interact_pointer_pos() {
-                        self.on_right_click(pos);
-                    }
+                if response.secondary_clicked()
+                    && let Some(pos) = response.interact_pointer_pos()
+                {
+                    self.on_right_click(pos);
                 }
 
                 // ---- Painting ------------------------------------------------------
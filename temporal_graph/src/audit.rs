@@ -0,0 +1,168 @@
+use crate::{LabelMove, TemporalGraph, TimeStep, VertexId};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Part of a minimization operation checked by the audit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditedStep {
+    /// Labels moved by the transfer through edge `through`.
+    Transfer {
+        through: (VertexId, VertexId),
+        moves: Vec<LabelMove>,
+    },
+
+    /// The `tmin` label of the wrappable edge moved to its new edge.
+    TminMove(LabelMove),
+}
+
+/// Step of a minimization run that changed the temporal reachability
+/// relation, found by `MinimizationConfig::with_audit`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReachabilityViolation {
+    /// Iteration of the run, counted from 1.
+    pub iteration: usize,
+    /// Wrappable edge `{u, v}` of the operation.
+    pub wrappable_edge: (VertexId, VertexId),
+    /// Minimum incident label `(w, x, t)` of the operation.
+    pub incident: (VertexId, VertexId, TimeStep),
+    pub step: AuditedStep,
+    /// Ordered pairs `(s, t)` reachable before the step but not after.
+    pub pairs_lost: Vec<(VertexId, VertexId)>,
+    /// Ordered pairs `(s, t)` reachable after the step but not before.
+    pub pairs_gained: Vec<(VertexId, VertexId)>,
+}
+
+/// Ordered pairs whose reachability changed, see `ReachabilityAudit::check`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ReachabilityDiff {
+    /// Pairs reachable in the starting graph but not anymore.
+    pub(crate) lost: Vec<(VertexId, VertexId)>,
+    /// Pairs reachable now but not in the starting graph.
+    pub(crate) gained: Vec<(VertexId, VertexId)>,
+}
+
+/// Reachable ordered pairs of the graph a run started from, compared with
+/// the graph after every audited step.
+///
+/// Every step must keep the relation unchanged, so comparing with the
+/// starting pairs is the same as comparing before and after the step.
+#[derive(Debug, Clone)]
+pub(crate) struct ReachabilityAudit {
+    strict: bool,
+    expected: BTreeSet<(VertexId, VertexId)>,
+}
+
+impl ReachabilityAudit {
+    pub(crate) fn new(graph: &TemporalGraph, strict: bool) -> Self {
+        Self {
+            strict,
+            expected: graph.reachability_graph(strict).arcs,
+        }
+    }
+
+    /// Pairs lost and gained by `graph`, or `None` if it has the same
+    /// reachability as the starting graph
+    pub(crate) fn check(&self, graph: &TemporalGraph) -> Option<ReachabilityDiff> {
+        let arcs = graph.reachability_graph(self.strict).arcs;
+        if arcs == self.expected {
+            return None;
+        }
+
+        Some(ReachabilityDiff {
+            lost: self.expected.difference(&arcs).copied().collect(),
+            gained: arcs.difference(&self.expected).copied().collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimization::tests::cycling_sample;
+    use crate::{MinimizationConfig, TerminationReason};
+
+    #[test]
+    fn test_tmin_move_violation() {
+        // Moving the label at 1 from {0, 3} to {0, 2} creates the journey
+        // 0 - 2 - 1
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 3, 1);
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 3);
+        g.add_edge(0, 3, 4);
+
+        let result = g
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new().with_audit());
+        assert_eq!(
+            result.termination_reason,
+            TerminationReason::ReachabilityViolated
+        );
+        assert!(result.verdict.is_undetermined());
+
+        let violation = result.audit_violation.unwrap();
+        assert_eq!(violation.iteration, 1);
+        assert_eq!(violation.wrappable_edge, (0, 3));
+        assert_eq!(
+            violation.step,
+            AuditedStep::TminMove(result.operations[0].wrapped)
+        );
+        assert!(violation.pairs_lost.is_empty());
+        assert_eq!(violation.pairs_gained, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_transfer_violation() {
+        let mut g = TemporalGraph::new();
+        for (u, v, t) in [(0, 3, 1), (1, 3, 1), (0, 2, 2), (1, 3, 4), (0, 3, 5)] {
+            g.add_edge(u, v, t);
+        }
+
+        let result = g
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new().with_audit());
+        let violation = result.audit_violation.unwrap();
+        assert!(matches!(
+            violation.step,
+            AuditedStep::Transfer {
+                through: (0, 3),
+                ..
+            }
+        ));
+        assert_eq!(violation.pairs_gained, vec![(2, 1)]);
+        // The operation stopped halfway is not recorded
+        assert!(result.operations.is_empty());
+    }
+
+    #[test]
+    fn test_audit_does_not_change_clean_runs() {
+        let g = cycling_sample();
+
+        let plain = g
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new());
+        let audited = g
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new().with_audit());
+        assert_eq!(audited.verdict, plain.verdict);
+        assert_eq!(audited.operations, plain.operations);
+        assert_eq!(audited.audit_violation, None);
+    }
+
+    #[test]
+    fn test_check_lists_lost_and_gained_pairs() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        let audit = ReachabilityAudit::new(&g, true);
+        assert_eq!(audit.check(&g), None);
+
+        // Moving the label at 2 from {1, 2} to {0, 2} keeps 0 -> 2 and
+        // 1 -> 2, loses 2 -> 1 and gains 2 -> 0
+        g.remove_edge_timestamp(1, 2, 2);
+        g.add_edge(0, 2, 2);
+        let diff = audit.check(&g).unwrap();
+        assert_eq!(diff.lost, vec![(2, 1)]);
+        assert_eq!(diff.gained, vec![(2, 0)]);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::minimization::{redundant_contacts, wrap_edge};
+use crate::{GraphState, MinimizationOperation, TemporalGraph, TimeStep, VertexId};
+use std::collections::{HashMap, VecDeque};
+
+/// Order in which `search_minimization_branches` visits states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchOrder {
+    BreadthFirst,
+    DepthFirst,
+}
+
+/// Configuration for `search_minimization_branches`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchSearchConfig {
+    pub order: SearchOrder,
+    /// Maximum number of distinct states to explore.
+    pub max_states: Option<usize>,
+    /// Whether journeys use strictly increasing timestamps when checking
+    /// which labels are redundant.
+    pub strict: bool,
+}
+
+impl Default for BranchSearchConfig {
+    fn default() -> Self {
+        Self {
+            order: SearchOrder::BreadthFirst,
+            max_states: Some(100_000),
+            strict: true,
+        }
+    }
+}
+
+impl BranchSearchConfig {
+    /// Create a new configuration with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder method: explore depth-first
+    pub fn depth_first(mut self) -> Self {
+        self.order = SearchOrder::DepthFirst;
+        self
+    }
+
+    /// Builder method: set the maximum number of states
+    pub fn with_max_states(mut self, max: usize) -> Self {
+        self.max_states = Some(max);
+        self
+    }
+
+    /// Builder method: explore the whole state graph
+    pub fn unlimited_states(mut self) -> Self {
+        self.max_states = None;
+        self
+    }
+
+    /// Builder method: use non-decreasing timestamps for journeys
+    pub fn non_strict(mut self) -> Self {
+        self.strict = false;
+        self
+    }
+}
+
+/// Outcome of an exhaustive search over the choices of `LabelMinimizer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchSearchReport {
+    /// Shortest sequence of operations found leading to a state without
+    /// wrappable edge that has a redundant contact.
+    pub shortest_branch: Option<Vec<MinimizationOperation>>,
+    /// State reached by `shortest_branch`.
+    pub proof_state: Option<GraphState>,
+    /// Contacts of `proof_state` whose removal keeps every pair reachable
+    /// in the input graph reachable; never empty when a branch is found.
+    pub redundant_contacts: Vec<(VertexId, VertexId, TimeStep)>,
+    /// Number of distinct states explored.
+    pub states_explored: usize,
+    /// Number of transitions between explored states.
+    pub transitions: usize,
+    /// Whether every reachable state was explored.
+    pub complete: bool,
+}
+
+impl BranchSearchReport {
+    /// Check whether some branch proves non-minimality
+    pub fn proves_non_minimality(&self) -> bool {
+        self.shortest_branch.is_some()
+    }
+}
+
+impl TemporalGraph {
+    /// Explore every sequence of choices the minimizer could make.
+    ///
+    /// From each state, every wrappable edge is wrapped with every incident
+    /// label in its range, not only the earliest one. States are memoized,
+    /// so the search walks the graph of distinct `GraphState`s; once it is
+    /// explored (or `max_states` is hit), the shortest branch to a state
+    /// without wrappable edge is extracted by a BFS over the recorded
+    /// transitions. Such a dead end only proves non-minimality if one of
+    /// its contacts can be removed while keeping the reachable pairs of the
+    /// input graph, as in `LabelMinimizer`; dead ends without such a
+    /// contact are skipped. The order only matters when the state limit
+    /// cuts the search short.
+    ///
+    /// # Parameters
+    /// - `config`: visiting order, state limit and journey semantics.
+    pub fn search_minimization_branches(&self, config: &BranchSearchConfig) -> BranchSearchReport {
+        let mut index: HashMap<GraphState, usize> = HashMap::new();
+        let mut states: Vec<GraphState> = Vec::new();
+        let mut graphs: Vec<Option<TemporalGraph>> = Vec::new();
+        let mut successors: Vec<Vec<(usize, MinimizationOperation)>> = Vec::new();
+        let mut dead_ends: Vec<Option<TemporalGraph>> = Vec::new();
+
+        let root = self.clone_graph();
+        index.insert(root.to_state(), 0);
+        states.push(root.to_state());
+        graphs.push(Some(root));
+        successors.push(Vec::new());
+        dead_ends.push(None);
+
+        let mut frontier = VecDeque::from([0]);
+        let mut complete = true;
+
+        while let Some(current) = match config.order {
+            SearchOrder::BreadthFirst => frontier.pop_front(),
+            SearchOrder::DepthFirst => frontier.pop_back(),
+        } {
+            let graph = graphs[current].take().expect("states are expanded once");
+            let wrappable = graph.wrappable_edges();
+            if wrappable.is_empty() {
+                dead_ends[current] = Some(graph);
+                continue;
+            }
+
+            for candidate in wrappable {
+                let (u, v) = candidate.edge;
+                for incident in graph.incident_labels_in_range(u, v) {
+                    let mut next = graph.clone_graph();
+                    let Some(operation) =
+                        wrap_edge(&mut next, candidate.edge, incident, |_, _| true)
+                    else {
+                        continue;
+                    };
+
+                    let state = next.to_state();
+                    let target = match index.get(&state) {
+                        Some(&known) => known,
+                        None => {
+                            if config.max_states.is_some_and(|max| states.len() >= max) {
+                                complete = false;
+                                continue;
+                            }
+                            let id = states.len();
+                            index.insert(state.clone(), id);
+                            states.push(state);
+                            graphs.push(Some(next));
+                            successors.push(Vec::new());
+                            dead_ends.push(None);
+                            frontier.push_back(id);
+                            id
+                        }
+                    };
+                    successors[current].push((target, operation));
+                }
+            }
+        }
+
+        // Shortest branch to a dead end with a redundant contact over the
+        // recorded transitions
+        let expected = self.reachability_graph(config.strict).arcs;
+        let mut redundant = Vec::new();
+        let mut parent: Vec<Option<(usize, usize)>> = vec![None; states.len()];
+        let mut seen = vec![false; states.len()];
+        seen[0] = true;
+        let mut queue = VecDeque::from([0]);
+        let mut proof = None;
+        while let Some(current) = queue.pop_front() {
+            if let Some(graph) = &dead_ends[current] {
+                redundant = redundant_contacts(graph, &expected, config.strict, || None)
+                    .unwrap_or_default();
+                if !redundant.is_empty() {
+                    proof = Some(current);
+                    break;
+                }
+            }
+            for (k, &(next, _)) in successors[current].iter().enumerate() {
+                if !seen[next] {
+                    seen[next] = true;
+                    parent[next] = Some((current, k));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let shortest_branch = proof.map(|end| {
+            let mut branch = Vec::new();
+            let mut current = end;
+            while let Some((previous, k)) = parent[current] {
+                branch.push(successors[previous][k].1.clone());
+                current = previous;
+            }
+            branch.reverse();
+            branch
+        });
+
+        BranchSearchReport {
+            shortest_branch,
+            proof_state: proof.map(|end| states[end].clone()),
+            redundant_contacts: redundant,
+            states_explored: states.len(),
+            transitions: successors.iter().map(Vec::len).sum(),
+            complete,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinimizationConfig;
+    use crate::minimization::tests::cycling_sample;
+
+    #[test]
+    fn test_shortest_branch_replays() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 5);
+
+        let report = g.search_minimization_branches(&BranchSearchConfig::new());
+        assert!(report.proves_non_minimality());
+        assert!(report.complete);
+
+        let branch = report.shortest_branch.as_ref().unwrap();
+        let mut replayed = g.clone_graph();
+        for operation in branch {
+            assert!(operation.apply(&mut replayed));
+        }
+        assert_eq!(Some(replayed.to_state()), report.proof_state);
+        assert!(replayed.find_wrappable_edge().is_none());
+        assert!(!report.redundant_contacts.is_empty());
+        for &(u, v, t) in &report.redundant_contacts {
+            let mut reduced = replayed.clone_graph();
+            reduced.remove_edge_timestamp(u, v, t);
+            assert_eq!(
+                reduced.reachability_graph(true).arcs,
+                g.reachability_graph(true).arcs
+            );
+        }
+
+        // The greedy run is one branch, so the shortest is no longer
+        let greedy = g
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new());
+        assert!(branch.len() <= greedy.operations.len());
+    }
+
+    #[test]
+    fn test_no_branch_on_cycling_graph() {
+        // Triangle where every wrap moves the label at time 1 around
+        let g = cycling_sample();
+
+        for config in [
+            BranchSearchConfig::new(),
+            BranchSearchConfig::new().depth_first(),
+        ] {
+            let report = g.search_minimization_branches(&config);
+            assert!(!report.proves_non_minimality());
+            assert!(report.complete);
+            assert!(report.states_explored > 1);
+            assert!(report.transitions >= report.states_explored);
+        }
+    }
+
+    #[test]
+    fn test_state_limit() {
+        let g = cycling_sample();
+
+        let report = g.search_minimization_branches(&BranchSearchConfig::new().with_max_states(1));
+        assert_eq!(report.states_explored, 1);
+        assert!(!report.complete);
+        assert!(!report.proves_non_minimality());
+    }
+
+    #[test]
+    fn test_graph_without_wrappable_edge() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+
+        let report = g.search_minimization_branches(&BranchSearchConfig::new());
+        assert_eq!(report.shortest_branch, None);
+        assert!(!report.proves_non_minimality());
+        assert_eq!(report.states_explored, 1);
+        assert_eq!(report.transitions, 0);
+    }
+
+    #[test]
+    fn test_dead_end_without_redundant_contact() {
+        // Label-minimal, yet the minimizer gets stuck after one wrap
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+
+        let report = g.search_minimization_branches(&BranchSearchConfig::new());
+        assert!(report.complete);
+        assert!(report.states_explored > 1);
+        assert!(!report.proves_non_minimality());
+        assert!(report.redundant_contacts.is_empty());
+    }
+}
@@ -0,0 +1,465 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// How the temporal distance between two vertices is measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemporalDistance {
+    /// Arrival time of the foremost journey, counted from the earliest
+    /// label of the graph (a journey arriving at that label has distance 1).
+    Foremost,
+
+    /// Duration `arrival - departure + 1` of the fastest journey.
+    Fastest,
+
+    /// Number of hops of the journey with fewest contacts.
+    Shortest,
+}
+
+/// Which optimal journeys betweenness counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OptimalJourneys {
+    /// Journeys with the minimum number of hops.
+    Shortest,
+
+    /// Journeys arriving at the earliest possible time, and among those the
+    /// ones with the minimum number of hops.
+    Foremost,
+}
+
+impl TemporalGraph {
+    /// Compute the temporal distance from `source` to every reachable vertex.
+    ///
+    /// Unreachable vertices are absent from the map and the source itself is
+    /// not included, as in `earliest_arrival_times`.
+    ///
+    /// # Parameters
+    /// - `distance`: foremost, fastest or shortest distance.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_distances(
+        &self,
+        source: VertexId,
+        distance: TemporalDistance,
+        strict: bool,
+    ) -> HashMap<VertexId, TimeStep> {
+        match distance {
+            TemporalDistance::Foremost => {
+                let start = self.contacts().first().map(|&(_, _, t)| t).unwrap_or(0);
+                self.earliest_arrival_times(source, strict)
+                    .into_iter()
+                    .map(|(v, arrival)| (v, arrival - start + 1))
+                    .collect()
+            }
+            TemporalDistance::Fastest => self.fastest_durations(source, strict),
+            TemporalDistance::Shortest => self.min_hop_counts(source, strict),
+        }
+    }
+
+    /// Compute harmonic temporal closeness for every vertex.
+    ///
+    /// The closeness of `s` is the sum of `1 / d(s, t)` over all vertices
+    /// `t` reachable from `s`; unreachable vertices contribute zero. Sources
+    /// are processed in parallel.
+    ///
+    /// # Parameters
+    /// - `distance`: distance used for `d(s, t)`.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_closeness(
+        &self,
+        distance: TemporalDistance,
+        strict: bool,
+    ) -> HashMap<VertexId, f64> {
+        self.vertices()
+            .par_iter()
+            .map(|&s| {
+                let closeness = self
+                    .temporal_distances(s, distance, strict)
+                    .values()
+                    .map(|&d| 1.0 / d as f64)
+                    .sum();
+                (s, closeness)
+            })
+            .collect()
+    }
+
+    /// Compute the temporal eccentricity of every vertex.
+    ///
+    /// The eccentricity of `s` is the largest distance from `s` to another
+    /// vertex, or `None` if some vertex cannot be reached from `s`. A vertex
+    /// alone in the graph has eccentricity 0.
+    ///
+    /// # Parameters
+    /// - `distance`: distance used for `d(s, t)`.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_eccentricity(
+        &self,
+        distance: TemporalDistance,
+        strict: bool,
+    ) -> HashMap<VertexId, Option<TimeStep>> {
+        let others = self.vertex_count().saturating_sub(1);
+
+        self.vertices()
+            .par_iter()
+            .map(|&s| {
+                let distances = self.temporal_distances(s, distance, strict);
+                let eccentricity = if distances.len() == others {
+                    Some(distances.values().copied().max().unwrap_or(0))
+                } else {
+                    None
+                };
+                (s, eccentricity)
+            })
+            .collect()
+    }
+
+    /// Compute the temporal diameter: the largest eccentricity.
+    ///
+    /// Returns `None` if the graph is not temporally connected.
+    ///
+    /// # Parameters
+    /// - `distance`: distance used for `d(s, t)`.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_diameter(&self, distance: TemporalDistance, strict: bool) -> Option<TimeStep> {
+        self.temporal_eccentricity(distance, strict)
+            .into_values()
+            .try_fold(0, |diameter, eccentricity| {
+                eccentricity.map(|e| diameter.max(e))
+            })
+    }
+
+    /// Compute temporal betweenness for every vertex.
+    ///
+    /// For each ordered pair `(s, t)` of distinct vertices with `σ_st`
+    /// optimal journeys, a vertex `v` other than `s` and `t` receives
+    /// `σ_st(v) / σ_st`, where `σ_st(v)` counts the optimal journeys passing
+    /// through `v`. Optimal journeys never revisit a vertex, so they are
+    /// counted exactly on the hop-layered expansion of the graph. Values are
+    /// not normalized. Sources are processed in parallel.
+    ///
+    /// # Parameters
+    /// - `journeys`: shortest or foremost journeys.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_betweenness(
+        &self,
+        journeys: OptimalJourneys,
+        strict: bool,
+    ) -> HashMap<VertexId, f64> {
+        let vertices = self.vertices();
+        let contact_lists = self.contact_lists();
+
+        let partial: Vec<HashMap<VertexId, f64>> = vertices
+            .par_iter()
+            .map(|&s| self.betweenness_from(s, journeys, strict, &contact_lists))
+            .collect();
+
+        let mut betweenness: HashMap<VertexId, f64> = vertices.iter().map(|&v| (v, 0.0)).collect();
+        for scores in partial {
+            for (v, score) in scores {
+                *betweenness.entry(v).or_insert(0.0) += score;
+            }
+        }
+        betweenness
+    }
+
+    /// Minimum duration of a journey from `source` to each vertex
+    fn fastest_durations(&self, source: VertexId, strict: bool) -> HashMap<VertexId, TimeStep> {
+        let mut departures: Vec<TimeStep> = self
+            .contact_lists()
+            .remove(&source)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, t)| t)
+            .collect();
+        departures.dedup();
+
+        // Every journey starts with one of the source's labels, so the
+        // minimum over these departure times is exact
+        let mut best: HashMap<VertexId, TimeStep> = HashMap::new();
+        for departure in departures {
+            for (v, arrival) in self.earliest_arrival_times_from(source, departure, strict) {
+                let duration = arrival - departure + 1;
+                best.entry(v)
+                    .and_modify(|d| *d = (*d).min(duration))
+                    .or_insert(duration);
+            }
+        }
+        best
+    }
+
+    /// Minimum number of hops of a journey from `source` to each vertex
+    fn min_hop_counts(&self, source: VertexId, strict: bool) -> HashMap<VertexId, TimeStep> {
+        let mut hops: HashMap<VertexId, TimeStep> = HashMap::new();
+
+        if !self.has_vertex(source) {
+            return hops;
+        }
+
+        let contacts = self.contacts();
+
+        // arrival[v] = earliest arrival at v using at most `k` hops; an earlier
+        // arrival with the same hop budget always extends at least as well
+        let mut arrival: HashMap<VertexId, TimeStep> = HashMap::new();
+        arrival.insert(source, TimeStep::MIN);
+
+        for k in 1..self.vertex_count() as TimeStep {
+            let mut next = arrival.clone();
+            for &(u, v, t) in &contacts {
+                for (a, b) in [(u, v), (v, u)] {
+                    let usable = match arrival.get(&a) {
+                        Some(&TimeStep::MIN) => true,
+                        Some(&last) if strict => t > last,
+                        Some(&last) => t >= last,
+                        None => false,
+                    };
+                    if usable && next.get(&b).is_none_or(|&prev| t < prev) {
+                        next.insert(b, t);
+                    }
+                }
+            }
+
+            if next == arrival {
+                break;
+            }
+            for &v in next.keys() {
+                if v != source && !hops.contains_key(&v) {
+                    hops.insert(v, k);
+                }
+            }
+            arrival = next;
+        }
+
+        hops
+    }
+
+    /// Betweenness contributions of all journeys starting at `source`
+    fn betweenness_from(
+        &self,
+        source: VertexId,
+        journeys: OptimalJourneys,
+        strict: bool,
+        contact_lists: &HashMap<VertexId, Vec<(VertexId, TimeStep)>>,
+    ) -> HashMap<VertexId, f64> {
+        let layers = JourneyLayers::build(source, strict, self.vertex_count(), contact_lists);
+        let foremost = match journeys {
+            OptimalJourneys::Foremost => Some(self.earliest_arrival_times(source, strict)),
+            OptimalJourneys::Shortest => None,
+        };
+
+        let mut scores: HashMap<VertexId, f64> = HashMap::new();
+
+        for target in self.vertices() {
+            if target == source {
+                continue;
+            }
+
+            let admissible = |&(v, a): &(VertexId, TimeStep)| {
+                v == target
+                    && foremost
+                        .as_ref()
+                        .is_none_or(|arrivals| arrivals.get(&target) == Some(&a))
+            };
+
+            // The first layer holding an admissible node gives the hop count
+            let Some(depth) =
+                (1..layers.nodes.len()).find(|&k| layers.nodes[k].iter().any(admissible))
+            else {
+                continue;
+            };
+
+            let mut backward: Vec<Vec<f64>> = layers
+                .nodes
+                .iter()
+                .map(|layer| vec![0.0; layer.len()])
+                .collect();
+            let mut total = 0.0;
+            for (i, node) in layers.nodes[depth].iter().enumerate() {
+                if admissible(node) {
+                    backward[depth][i] = 1.0;
+                    total += layers.forward[depth][i];
+                }
+            }
+
+            for k in (1..=depth).rev() {
+                for i in 0..layers.nodes[k].len() {
+                    let weight = backward[k][i];
+                    if weight == 0.0 {
+                        continue;
+                    }
+                    for &p in &layers.predecessors[k][i] {
+                        backward[k - 1][p] += weight;
+                    }
+                }
+            }
+
+            for (k, weights) in backward.iter().enumerate().take(depth).skip(1) {
+                for (i, &(v, _)) in layers.nodes[k].iter().enumerate() {
+                    let through = layers.forward[k][i] * weights[i];
+                    if through > 0.0 {
+                        *scores.entry(v).or_insert(0.0) += through / total;
+                    }
+                }
+            }
+        }
+
+        scores
+    }
+}
+
+/// Hop-layered expansion of the journeys leaving one source.
+///
+/// Layer `k` holds the `(vertex, arrival)` states reachable with exactly `k`
+/// hops, how many `k`-hop journeys reach each of them, and the states of
+/// layer `k - 1` they extend.
+struct JourneyLayers {
+    nodes: Vec<Vec<(VertexId, TimeStep)>>,
+    forward: Vec<Vec<f64>>,
+    predecessors: Vec<Vec<Vec<usize>>>,
+}
+
+impl JourneyLayers {
+    fn build(
+        source: VertexId,
+        strict: bool,
+        vertex_count: usize,
+        contact_lists: &HashMap<VertexId, Vec<(VertexId, TimeStep)>>,
+    ) -> Self {
+        let mut layers = JourneyLayers {
+            nodes: vec![vec![(source, TimeStep::MIN)]],
+            forward: vec![vec![1.0]],
+            predecessors: vec![vec![Vec::new()]],
+        };
+
+        // Optimal journeys are paths, so they have at most n - 1 hops
+        for _ in 1..vertex_count {
+            let previous = layers.nodes.last().unwrap();
+            let previous_forward = layers.forward.last().unwrap();
+
+            let mut index: HashMap<(VertexId, TimeStep), usize> = HashMap::new();
+            let mut nodes = Vec::new();
+            let mut forward = Vec::new();
+            let mut predecessors: Vec<Vec<usize>> = Vec::new();
+
+            for (p, &(u, last)) in previous.iter().enumerate() {
+                for &(w, t) in contact_lists.get(&u).into_iter().flatten() {
+                    let usable = last == TimeStep::MIN || if strict { t > last } else { t >= last };
+                    if !usable || w == source {
+                        continue;
+                    }
+                    let i = *index.entry((w, t)).or_insert_with(|| {
+                        nodes.push((w, t));
+                        forward.push(0.0);
+                        predecessors.push(Vec::new());
+                        nodes.len() - 1
+                    });
+                    forward[i] += previous_forward[p];
+                    predecessors[i].push(p);
+                }
+            }
+
+            if nodes.is_empty() {
+                break;
+            }
+            layers.nodes.push(nodes);
+            layers.forward.push(forward);
+            layers.predecessors.push(predecessors);
+        }
+
+        layers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Path 0 -[1]- 1 -[2]- 2 -[3]- 3, as in the connectivity tests
+    fn path_graph() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 3, 3);
+        g
+    }
+
+    #[test]
+    fn test_distances_on_path() {
+        let g = path_graph();
+
+        let foremost = g.temporal_distances(0, TemporalDistance::Foremost, true);
+        assert_eq!(foremost.get(&3), Some(&3));
+
+        let fastest = g.temporal_distances(1, TemporalDistance::Fastest, true);
+        assert_eq!(fastest.get(&3), Some(&2)); // leave at 2, arrive at 3
+
+        let shortest = g.temporal_distances(0, TemporalDistance::Shortest, true);
+        assert_eq!(shortest.get(&1), Some(&1));
+        assert_eq!(shortest.get(&3), Some(&3));
+    }
+
+    #[test]
+    fn test_shortest_prefers_fewer_hops_over_earlier_arrival() {
+        // 0 reaches 2 in one hop at t=10, or in two hops by t=2
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 10);
+
+        let shortest = g.temporal_distances(0, TemporalDistance::Shortest, true);
+        assert_eq!(shortest.get(&2), Some(&1));
+        let foremost = g.temporal_distances(0, TemporalDistance::Foremost, true);
+        assert_eq!(foremost.get(&2), Some(&2));
+    }
+
+    #[test]
+    fn test_closeness_harmonic() {
+        let g = path_graph();
+        let closeness = g.temporal_closeness(TemporalDistance::Shortest, true);
+        let expected = 1.0 + 1.0 / 2.0 + 1.0 / 3.0;
+        assert!((closeness[&0] - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eccentricity_and_diameter() {
+        let g = path_graph();
+        let eccentricity = g.temporal_eccentricity(TemporalDistance::Shortest, true);
+
+        // 0 reaches everything; 3 cannot reach 0 (needs t > 3 after t=3)
+        assert_eq!(eccentricity[&0], Some(3));
+        assert_eq!(eccentricity[&3], None);
+        assert_eq!(g.temporal_diameter(TemporalDistance::Shortest, true), None);
+
+        let mut pair = TemporalGraph::new();
+        pair.add_edge(0, 1, 1);
+        assert_eq!(
+            pair.temporal_diameter(TemporalDistance::Shortest, true),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_betweenness_on_path() {
+        let g = path_graph();
+        let betweenness = g.temporal_betweenness(OptimalJourneys::Shortest, true);
+
+        // Forward journeys 0->2, 0->3 and 1->3 pass through inner vertices;
+        // backward journeys exist only between neighbours
+        assert_eq!(betweenness[&0], 0.0);
+        assert!((betweenness[&1] - 2.0).abs() < 1e-9);
+        assert!((betweenness[&2] - 2.0).abs() < 1e-9);
+        assert_eq!(betweenness[&3], 0.0);
+    }
+
+    #[test]
+    fn test_betweenness_splits_between_parallel_routes() {
+        // Two foremost routes 0 -> {1, 2} -> 3, both arriving at t=2
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 2, 1);
+        g.add_edge(1, 3, 2);
+        g.add_edge(2, 3, 2);
+
+        let betweenness = g.temporal_betweenness(OptimalJourneys::Foremost, true);
+        assert!((betweenness[&1] - 0.5).abs() < 1e-9);
+        assert!((betweenness[&2] - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,507 @@
+use crate::minimization::LabelMinimizer;
+use crate::{
+    GraphState, MinimizationConfig, MinimizationOperation, MinimizationResult, MinimizationStats,
+    SelectionPolicy, TemporalGraph, TimeStep, VertexId,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::time::Duration;
+
+/// Version of the checkpoint format written by this crate.
+///
+/// Version 2 keeps states in a separate log and always records whether the
+/// run was audited; version 1 checkpoints are rejected.
+pub const CHECKPOINT_VERSION: u32 = 2;
+
+/// Where and how often `LabelMinimizer` writes checkpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointSchedule {
+    /// Number of iterations between two checkpoints.
+    pub every: usize,
+    /// File overwritten by each checkpoint.
+    pub filename: String,
+}
+
+/// Vertices and contacts of a graph, enough to rebuild it for the
+/// minimizer; vertex labels are not kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphSnapshot {
+    pub vertices: Vec<VertexId>,
+    pub contacts: Vec<(VertexId, VertexId, TimeStep)>,
+}
+
+impl GraphSnapshot {
+    pub fn of(graph: &TemporalGraph) -> Self {
+        let mut vertices: Vec<VertexId> = graph.vertices.iter().copied().collect();
+        vertices.sort_unstable();
+        Self {
+            vertices,
+            contacts: graph.contacts(),
+        }
+    }
+
+    pub fn to_graph(&self) -> TemporalGraph {
+        let mut graph = TemporalGraph::new();
+        for &v in &self.vertices {
+            graph.add_vertex(v);
+        }
+        for &(u, v, t) in &self.contacts {
+            graph.add_edge(u, v, t);
+        }
+        graph
+    }
+}
+
+/// Serializable part of a `MinimizationConfig`.
+///
+/// Observers and cancellation tokens are not saved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckpointedConfig {
+    pub max_iterations: Option<usize>,
+    pub time_budget: Option<Duration>,
+    pub max_stored_states: Option<usize>,
+    pub track_statistics: bool,
+    pub verbose: bool,
+    pub strict: bool,
+    pub record_state_graph: bool,
+    /// `None` when the run used a strategy other than `SelectionPolicy`,
+    /// which has to be supplied again on resume.
+    pub selection: Option<SelectionPolicy>,
+    pub checkpoint: Option<CheckpointSchedule>,
+    pub audit: bool,
+    pub find_redundant_contacts: bool,
+}
+
+impl CheckpointedConfig {
+    pub fn of(config: &MinimizationConfig) -> Self {
+        Self {
+            max_iterations: config.max_iterations,
+            time_budget: config.time_budget,
+            max_stored_states: config.max_stored_states,
+            track_statistics: config.track_statistics,
+            verbose: config.verbose,
+            strict: config.strict,
+            record_state_graph: config.record_state_graph,
+            selection: config.selection.policy(),
+            checkpoint: config.checkpoint.clone(),
+            audit: config.audit,
+            find_redundant_contacts: config.find_redundant_contacts,
+        }
+    }
+}
+
+/// Progress of a minimization run, written every few iterations so that
+/// the run can be resumed after the process dies.
+///
+/// Full states are kept rather than fingerprints, so that a resumed run
+/// still reports cycle evidence and the state graph. `save` writes them to
+/// a state log next to the checkpoint file, one compact JSON line per
+/// state, so that a run only appends the states it visited since its
+/// previous checkpoint instead of rewriting all of them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinimizationCheckpoint {
+    /// Format version, `CHECKPOINT_VERSION` when written.
+    pub version: u32,
+    /// Input graph of the run.
+    pub initial: GraphSnapshot,
+    /// Graph at the end of the last completed iteration.
+    pub graph: GraphSnapshot,
+    /// Seen states in visit order; state 0 is the input graph.
+    pub seen_states: Vec<GraphState>,
+    pub operations: Vec<MinimizationOperation>,
+    pub stats: MinimizationStats,
+    pub config: CheckpointedConfig,
+}
+
+/// Checkpoint file contents: a `MinimizationCheckpoint` whose states are in
+/// the state log, of which only the first `state_count` lines count.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CheckpointHeader {
+    pub(crate) version: u32,
+    pub(crate) initial: GraphSnapshot,
+    pub(crate) graph: GraphSnapshot,
+    pub(crate) state_count: usize,
+    pub(crate) operations: Vec<MinimizationOperation>,
+    pub(crate) stats: MinimizationStats,
+    pub(crate) config: CheckpointedConfig,
+}
+
+impl CheckpointHeader {
+    /// Write the header to `filename`, through a temporary file so that a
+    /// crash never leaves a truncated checkpoint
+    pub(crate) fn save(&self, filename: &str) -> std::io::Result<()> {
+        let json = serde_json::to_string(self).expect("checkpoints serialize to JSON");
+        let temporary = format!("{}.tmp", filename);
+        fs::write(&temporary, json)?;
+        fs::rename(&temporary, filename)
+    }
+}
+
+/// State log of the checkpoint file `filename`
+pub fn state_log_path(filename: &str) -> String {
+    format!("{}.states", filename)
+}
+
+/// Write `states` to the state log of `filename`, either appended to it or
+/// replacing it atomically.
+///
+/// Lines past the count of the header are ignored on load, so appending
+/// before writing the header never corrupts the previous checkpoint.
+pub(crate) fn write_state_log(
+    filename: &str,
+    states: &[GraphState],
+    append: bool,
+) -> std::io::Result<()> {
+    let mut lines = String::new();
+    for state in states {
+        lines.push_str(&serde_json::to_string(state).expect("states serialize to JSON"));
+        lines.push('\n');
+    }
+
+    let path = state_log_path(filename);
+    if append {
+        fs::OpenOptions::new()
+            .append(true)
+            .open(&path)?
+            .write_all(lines.as_bytes())
+    } else {
+        let temporary = format!("{}.tmp", path);
+        fs::write(&temporary, lines)?;
+        fs::rename(&temporary, &path)
+    }
+}
+
+/// Fail unless `value` has the current format version
+fn check_version(value: &serde_json::Value) -> Result<(), String> {
+    match value.get("version").and_then(serde_json::Value::as_u64) {
+        Some(version) if version == u64::from(CHECKPOINT_VERSION) => Ok(()),
+        Some(version) => Err(format!(
+            "Unsupported checkpoint version {} (expected {})",
+            version, CHECKPOINT_VERSION
+        )),
+        None => Err("Invalid checkpoint: missing version".to_string()),
+    }
+}
+
+impl MinimizationCheckpoint {
+    /// Serialize to compact JSON, states included
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("checkpoints serialize to JSON")
+    }
+
+    /// Parse a checkpoint written by `to_json`, rejecting other versions
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| format!("Invalid checkpoint: {}", e))?;
+        check_version(&value)?;
+        serde_json::from_value(value).map_err(|e| format!("Invalid checkpoint: {}", e))
+    }
+
+    /// Write the checkpoint to `filename` and its states to the state log
+    /// `state_log_path(filename)`, replacing both
+    pub fn save(&self, filename: &str) -> std::io::Result<()> {
+        write_state_log(filename, &self.seen_states, false)?;
+        CheckpointHeader {
+            version: self.version,
+            initial: self.initial.clone(),
+            graph: self.graph.clone(),
+            state_count: self.seen_states.len(),
+            operations: self.operations.clone(),
+            stats: self.stats.clone(),
+            config: self.config.clone(),
+        }
+        .save(filename)
+    }
+
+    /// Read a checkpoint written by `save` or by a run, with its state log
+    pub fn load(filename: &str) -> Result<Self, String> {
+        let json = fs::read_to_string(filename)
+            .map_err(|e| format!("Failed to read {}: {}", filename, e))?;
+        let value: serde_json::Value =
+            serde_json::from_str(&json).map_err(|e| format!("Invalid checkpoint: {}", e))?;
+        check_version(&value)?;
+        let header: CheckpointHeader =
+            serde_json::from_value(value).map_err(|e| format!("Invalid checkpoint: {}", e))?;
+
+        let path = state_log_path(filename);
+        let log =
+            fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let seen_states = log
+            .lines()
+            .take(header.state_count)
+            .map(|line| serde_json::from_str(line).map_err(|e| format!("Invalid state log: {}", e)))
+            .collect::<Result<Vec<GraphState>, String>>()?;
+        if seen_states.len() != header.state_count {
+            return Err(format!(
+                "Invalid state log: {} states instead of {}",
+                seen_states.len(),
+                header.state_count
+            ));
+        }
+
+        Ok(Self {
+            version: header.version,
+            initial: header.initial,
+            graph: header.graph,
+            seen_states,
+            operations: header.operations,
+            stats: header.stats,
+            config: header.config,
+        })
+    }
+
+    /// Rebuild the configuration of the checkpointed run.
+    ///
+    /// Fails if the run used a custom selection strategy; build the
+    /// configuration by hand with `with_selection` in that case.
+    pub fn config(&self) -> Result<MinimizationConfig, String> {
+        let saved = &self.config;
+        let selection = saved.selection.ok_or_else(|| {
+            "Checkpoint was written with a custom selection strategy, which must be supplied again"
+                .to_string()
+        })?;
+
+        Ok(MinimizationConfig {
+            max_iterations: saved.max_iterations,
+            time_budget: saved.time_budget,
+            max_stored_states: saved.max_stored_states,
+            track_statistics: saved.track_statistics,
+            verbose: saved.verbose,
+            strict: saved.strict,
+            record_state_graph: saved.record_state_graph,
+            checkpoint: saved.checkpoint.clone(),
+            audit: saved.audit,
+            find_redundant_contacts: saved.find_redundant_contacts,
+            ..MinimizationConfig::new()
+        }
+        .with_selection(selection))
+    }
+}
+
+impl TemporalGraph {
+    /// Resume the run saved in `checkpoint` and return its graph with the
+    /// result, as if the run had never stopped.
+    ///
+    /// # Parameters
+    /// - `config`: configuration of the resumed run, usually
+    ///   `checkpoint.config()` plus observers or a cancellation token.
+    pub fn resume_minimization(
+        checkpoint: &MinimizationCheckpoint,
+        config: MinimizationConfig,
+    ) -> (TemporalGraph, MinimizationResult) {
+        let mut graph = TemporalGraph::new();
+        let result = LabelMinimizer::from_checkpoint(&mut graph, checkpoint, config).run();
+        (graph, result)
+    }
+
+    /// Same as `resume_minimization` on the checkpoint in `filename`, with
+    /// its saved configuration
+    pub fn resume_minimization_file(
+        filename: &str,
+    ) -> Result<(TemporalGraph, MinimizationResult), String> {
+        let checkpoint = MinimizationCheckpoint::load(filename)?;
+        let config = checkpoint.config()?;
+        Ok(Self::resume_minimization(&checkpoint, config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimization::tests::{cycling_sample, redundant_sample};
+    use crate::{
+        CancellationToken, MinimizationObserver, SelectionStrategy, TerminationReason,
+        WrappableEdge,
+    };
+    use std::sync::Arc;
+
+    fn checkpoint_file(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("{}-{}.json", name, std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Simulates a process dying at the start of an iteration
+    #[derive(Debug)]
+    struct CancelAt {
+        iteration: usize,
+        token: CancellationToken,
+    }
+
+    impl MinimizationObserver for CancelAt {
+        fn iteration_started(&self, iteration: usize) {
+            if iteration == self.iteration {
+                self.token.cancel();
+            }
+        }
+    }
+
+    fn interrupted_and_resumed(
+        graph: &TemporalGraph,
+        config: MinimizationConfig,
+        iteration: usize,
+        name: &str,
+    ) -> (TemporalGraph, MinimizationResult) {
+        let filename = checkpoint_file(name);
+        let token = CancellationToken::new();
+        let interrupted = graph.clone_graph().is_label_minimal_with_config(
+            config
+                .with_checkpoints(1, &filename)
+                .with_cancellation(token.clone())
+                .with_observer(Arc::new(CancelAt { iteration, token })),
+        );
+        assert_eq!(interrupted.termination_reason, TerminationReason::Cancelled);
+
+        let resumed = TemporalGraph::resume_minimization_file(&filename).unwrap();
+        fs::remove_file(&filename).unwrap();
+        fs::remove_file(state_log_path(&filename)).unwrap();
+        resumed
+    }
+
+    #[test]
+    fn test_resumed_run_matches_uninterrupted_run() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 5);
+
+        let config = MinimizationConfig::new().with_statistics();
+        let uninterrupted = g.clone_graph().is_label_minimal_with_config(config.clone());
+        let (graph, resumed) = interrupted_and_resumed(&g, config, 2, "resume-useless");
+
+        assert_eq!(resumed.verdict, uninterrupted.verdict);
+        assert_eq!(resumed.operations, uninterrupted.operations);
+        assert_eq!(resumed.final_state, graph.to_state());
+        assert!(resumed.verify_certificate(&g, true));
+
+        let (resumed, uninterrupted) = (resumed.stats.unwrap(), uninterrupted.stats.unwrap());
+        assert_eq!(resumed.iterations, uninterrupted.iterations);
+        assert_eq!(resumed.states_visited, uninterrupted.states_visited);
+    }
+
+    #[test]
+    fn test_resume_keeps_redundant_contact_setting() {
+        let config = MinimizationConfig::new().without_redundant_contacts();
+        let uninterrupted = redundant_sample().is_label_minimal_with_config(config.clone());
+        let (_, resumed) =
+            interrupted_and_resumed(&redundant_sample(), config, 2, "resume-no-redundant");
+
+        assert_eq!(
+            resumed.termination_reason,
+            TerminationReason::UselessLabelFound
+        );
+        assert_eq!(resumed.verdict, uninterrupted.verdict);
+        assert!(resumed.verdict.is_undetermined());
+    }
+
+    #[test]
+    fn test_resumed_cycle_keeps_evidence() {
+        let g = cycling_sample();
+
+        let config = MinimizationConfig::new()
+            .with_statistics()
+            .with_state_graph()
+            .with_selection(SelectionPolicy::SeededRandom(5));
+        let uninterrupted = g.clone_graph().is_label_minimal_with_config(config.clone());
+        let (_, resumed) = interrupted_and_resumed(&g, config, 2, "resume-cycle");
+
+        assert!(resumed.verdict.is_minimal());
+        assert_eq!(resumed.verdict, uninterrupted.verdict);
+        assert_eq!(resumed.operations, uninterrupted.operations);
+        assert_eq!(resumed.state_graph, uninterrupted.state_graph);
+    }
+
+    // Checkpoint of a run that has not started yet
+    fn fresh_checkpoint(config: &MinimizationConfig) -> MinimizationCheckpoint {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        MinimizationCheckpoint {
+            version: CHECKPOINT_VERSION,
+            initial: GraphSnapshot::of(&g),
+            graph: GraphSnapshot::of(&g),
+            seen_states: vec![g.to_state()],
+            operations: Vec::new(),
+            stats: MinimizationStats::new(),
+            config: CheckpointedConfig::of(config),
+        }
+    }
+
+    #[test]
+    fn test_version_is_checked() {
+        let mut checkpoint = fresh_checkpoint(&MinimizationConfig::new());
+        assert_eq!(
+            MinimizationCheckpoint::from_json(&checkpoint.to_json()),
+            Ok(checkpoint.clone())
+        );
+
+        checkpoint.version = CHECKPOINT_VERSION + 1;
+        let error = MinimizationCheckpoint::from_json(&checkpoint.to_json()).unwrap_err();
+        assert!(error.contains("Unsupported checkpoint version"));
+        assert!(MinimizationCheckpoint::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_states_are_logged_once() {
+        let mut g = cycling_sample();
+
+        let filename = checkpoint_file("state-log");
+        let result = g.is_label_minimal_with_config(
+            MinimizationConfig::new()
+                .with_statistics()
+                .with_checkpoints(1, &filename),
+        );
+        let states_visited = result.stats.unwrap().states_visited;
+
+        let log = fs::read_to_string(state_log_path(&filename)).unwrap();
+        assert_eq!(log.lines().count(), states_visited);
+        let checkpoint = MinimizationCheckpoint::load(&filename).unwrap();
+        assert_eq!(checkpoint.seen_states.len(), states_visited);
+        assert_eq!(
+            checkpoint.seen_states[0],
+            checkpoint.initial.to_graph().to_state()
+        );
+
+        // A rewritten checkpoint loads back as it was saved
+        checkpoint.save(&filename).unwrap();
+        assert_eq!(MinimizationCheckpoint::load(&filename), Ok(checkpoint));
+        fs::remove_file(&filename).unwrap();
+        fs::remove_file(state_log_path(&filename)).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "checkpoint interval must be positive")]
+    fn test_zero_checkpoint_interval() {
+        MinimizationConfig::new().with_checkpoints(0, "unused.json");
+    }
+
+    #[test]
+    fn test_custom_strategy_must_be_supplied_again() {
+        #[derive(Debug)]
+        struct Last;
+
+        impl SelectionStrategy for Last {
+            fn select_edge(&self, candidates: &[WrappableEdge], _iteration: usize) -> usize {
+                candidates.len() - 1
+            }
+        }
+
+        let checkpoint = fresh_checkpoint(&MinimizationConfig::new().with_selection(Last));
+        assert_eq!(checkpoint.config.selection, None);
+        assert!(checkpoint.config().is_err());
+
+        let checkpoint = fresh_checkpoint(
+            &MinimizationConfig::new()
+                .non_strict()
+                .with_selection(SelectionPolicy::LargestSpan),
+        );
+        let config = checkpoint.config().unwrap();
+        assert_eq!(
+            config.selection.policy(),
+            Some(SelectionPolicy::LargestSpan)
+        );
+        assert!(!config.strict);
+    }
+}
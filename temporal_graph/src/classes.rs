@@ -0,0 +1,351 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Largest number of contacts `TemporalGraph::to_proper` searches exactly.
+pub const MAX_EXACT_PROPER_CONTACTS: usize = 10;
+
+/// Most specific structural class of a temporal graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemporalClass {
+    /// Proper and simple.
+    Happy,
+
+    /// Adjacent edges never share a timestamp, but some edge has several.
+    Proper,
+
+    /// One timestamp per edge, but adjacent edges may share one.
+    Simple,
+
+    /// None of the above.
+    General,
+}
+
+impl TemporalGraph {
+    /// Check whether every edge carries exactly one timestamp
+    pub fn is_simple(&self) -> bool {
+        self.edges.values().all(|edge| edge.timestamps.len() == 1)
+    }
+
+    /// Check whether, at every vertex, all incident contacts have pairwise
+    /// distinct timestamps
+    pub fn is_locally_injective(&self) -> bool {
+        let mut seen: HashSet<(VertexId, TimeStep)> = HashSet::new();
+
+        for ((u, v), edge) in &self.edges {
+            for &t in &edge.timestamps {
+                if !seen.insert((*u, t)) || !seen.insert((*v, t)) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Check whether adjacent edges never share a timestamp.
+    ///
+    /// The timestamps of a single edge form a set, so a proper labeling is
+    /// exactly a locally injective one. On proper graphs no journey can use
+    /// two contacts with the same timestamp, hence strict and non-strict
+    /// reachability coincide.
+    pub fn is_proper(&self) -> bool {
+        self.is_locally_injective()
+    }
+
+    /// Check whether the graph is both proper and simple
+    pub fn is_happy(&self) -> bool {
+        self.is_simple() && self.is_proper()
+    }
+
+    /// Classify the graph into its most specific structural class
+    pub fn temporal_class(&self) -> TemporalClass {
+        match (self.is_proper(), self.is_simple()) {
+            (true, true) => TemporalClass::Happy,
+            (true, false) => TemporalClass::Proper,
+            (false, true) => TemporalClass::Simple,
+            (false, false) => TemporalClass::General,
+        }
+    }
+
+    /// Check whether strict and non-strict reachability give the same
+    /// reachable pairs, in which case either connectivity mode is safe.
+    ///
+    /// Proper graphs answer immediately; otherwise both reachability
+    /// digraphs are computed and compared.
+    pub fn reachability_modes_agree(&self) -> bool {
+        self.is_proper()
+            || self.reachability_graph(true).arcs == self.reachability_graph(false).arcs
+    }
+
+    /// Relabel the graph into a proper one with the same reachable pairs,
+    /// by exhaustive search.
+    ///
+    /// On a proper graph only the order of adjacent contacts matters to
+    /// journeys, so every proper labeling is equivalent to one giving each
+    /// contact its own timestamp. After trying `to_proper_greedy`, contacts
+    /// are therefore given timestamps `0..m` one at a time by a depth-first
+    /// search, and the first complete order whose reachability digraph
+    /// equals the original one under `strict` is returned. Adding later
+    /// contacts never removes a journey, so a branch is pruned as soon as
+    /// its first contacts reach a pair the original graph does not. Labels
+    /// of one edge are interchangeable, so only their count matters.
+    ///
+    /// The search can still take up to `m!` reachability computations, so
+    /// graphs with more than `MAX_EXACT_PROPER_CONTACTS` contacts that the
+    /// greedy pass cannot relabel are rejected with an error; use
+    /// `to_proper_greedy` for them.
+    ///
+    /// Returns a copy of the graph if it is already proper, and `Ok(None)`
+    /// exactly when no proper relabeling exists: for instance, non-strict
+    /// journeys through a same-time path `a - b - c` run in both directions,
+    /// which no proper labeling of those two edges can reproduce.
+    ///
+    /// # Parameters
+    /// - `strict`: reachability semantics that must be preserved.
+    pub fn to_proper(&self, strict: bool) -> Result<Option<TemporalGraph>, String> {
+        if let Some(proper) = self.to_proper_greedy(strict) {
+            return Ok(Some(proper));
+        }
+
+        let contacts = self.contacts();
+        if contacts.len() > MAX_EXACT_PROPER_CONTACTS {
+            return Err(format!(
+                "Exact proper relabeling is limited to {} contacts, got {}",
+                MAX_EXACT_PROPER_CONTACTS,
+                contacts.len()
+            ));
+        }
+
+        let mut remaining: BTreeMap<(VertexId, VertexId), usize> = BTreeMap::new();
+        for &(u, v, _) in &contacts {
+            *remaining.entry((u, v)).or_default() += 1;
+        }
+        let mut remaining: Vec<_> = remaining.into_iter().collect();
+        let target = self.reachability_graph(strict).arcs;
+        let mut order = Vec::with_capacity(contacts.len());
+
+        Ok(self
+            .extend_proper_order(&mut remaining, &mut order, &target, strict)
+            .then(|| self.with_contacts(&order)))
+    }
+
+    /// Give the next timestamp to each edge with labels left in turn, and
+    /// recurse while the reachable pairs stay within `target`
+    fn extend_proper_order(
+        &self,
+        remaining: &mut [((VertexId, VertexId), usize)],
+        order: &mut Vec<(VertexId, VertexId, TimeStep)>,
+        target: &BTreeSet<(VertexId, VertexId)>,
+        strict: bool,
+    ) -> bool {
+        if remaining.iter().all(|&(_, count)| count == 0) {
+            return self.with_contacts(order).reachability_graph(strict).arcs == *target;
+        }
+
+        for i in 0..remaining.len() {
+            let ((u, v), count) = remaining[i];
+            if count == 0 {
+                continue;
+            }
+            remaining[i].1 -= 1;
+            order.push((u, v, order.len() as TimeStep));
+
+            let arcs = self.with_contacts(order).reachability_graph(strict).arcs;
+            if arcs.is_subset(target) && self.extend_proper_order(remaining, order, target, strict)
+            {
+                return true;
+            }
+
+            order.pop();
+            remaining[i].1 += 1;
+        }
+
+        false
+    }
+
+    /// Relabel the graph into a proper one with the same reachable pairs,
+    /// greedily.
+    ///
+    /// Timestamps are renumbered: contacts sharing a timestamp are spread
+    /// over consecutive new timestamps following a greedy edge colouring of
+    /// the contacts at that time, so that adjacent contacts get distinct
+    /// values while the relative order of distinct original times is kept.
+    /// Both orders of the colour classes are tried, and a candidate is kept
+    /// only if its reachability digraph equals the original one under
+    /// `strict`.
+    ///
+    /// Returns a copy of the graph if it is already proper, and `None` if
+    /// neither candidate preserves reachability. `None` does not prove that
+    /// no proper relabeling exists; `to_proper` decides it exactly.
+    ///
+    /// # Parameters
+    /// - `strict`: reachability semantics that must be preserved.
+    pub fn to_proper_greedy(&self, strict: bool) -> Option<TemporalGraph> {
+        if self.is_proper() {
+            return Some(self.clone_graph());
+        }
+
+        // Group contacts by time and colour each group
+        let mut groups: BTreeMap<TimeStep, Vec<(VertexId, VertexId)>> = BTreeMap::new();
+        for (u, v, t) in self.contacts() {
+            groups.entry(t).or_default().push((u, v));
+        }
+
+        let colourings: Vec<Vec<usize>> = groups
+            .values()
+            .map(|contacts| greedy_edge_colouring(contacts))
+            .collect();
+        let width = colourings
+            .iter()
+            .flat_map(|colours| colours.iter().map(|c| c + 1))
+            .max()
+            .unwrap_or(1) as TimeStep;
+
+        let target = self.reachability_graph(strict);
+
+        for reversed in [false, true] {
+            let mut relabeled = Vec::new();
+            for (rank, (contacts, colours)) in groups.values().zip(&colourings).enumerate() {
+                let base = rank as TimeStep * width;
+                for (&(u, v), &colour) in contacts.iter().zip(colours) {
+                    let offset = if reversed {
+                        width - 1 - colour as TimeStep
+                    } else {
+                        colour as TimeStep
+                    };
+                    relabeled.push((u, v, base + offset));
+                }
+            }
+            let candidate = self.with_contacts(&relabeled);
+
+            if candidate.reachability_graph(strict).arcs == target.arcs {
+                return Some(candidate);
+            }
+        }
+
+        None
+    }
+}
+
+/// Assign each edge the smallest colour unused by the edges sharing a vertex
+fn greedy_edge_colouring(edges: &[(VertexId, VertexId)]) -> Vec<usize> {
+    let mut used: HashMap<VertexId, HashSet<usize>> = HashMap::new();
+
+    edges
+        .iter()
+        .map(|&(u, v)| {
+            let colour = (0..)
+                .find(|c| {
+                    !used.get(&u).is_some_and(|cs| cs.contains(c))
+                        && !used.get(&v).is_some_and(|cs| cs.contains(c))
+                })
+                .unwrap();
+            used.entry(u).or_default().insert(colour);
+            used.entry(v).or_default().insert(colour);
+            colour
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        let mut happy = TemporalGraph::new();
+        happy.add_edge(0, 1, 1);
+        happy.add_edge(1, 2, 2);
+        assert_eq!(happy.temporal_class(), TemporalClass::Happy);
+
+        let mut proper = happy.clone_graph();
+        proper.add_edge(0, 1, 3);
+        assert_eq!(proper.temporal_class(), TemporalClass::Proper);
+
+        let mut simple = TemporalGraph::new();
+        simple.add_edge(0, 1, 1);
+        simple.add_edge(1, 2, 1);
+        assert_eq!(simple.temporal_class(), TemporalClass::Simple);
+        assert!(!simple.is_locally_injective());
+
+        let mut general = simple.clone_graph();
+        general.add_edge(0, 1, 2);
+        assert_eq!(general.temporal_class(), TemporalClass::General);
+    }
+
+    #[test]
+    fn test_reachability_modes_agree() {
+        let mut happy = TemporalGraph::new();
+        happy.add_edge(0, 1, 1);
+        happy.add_edge(1, 2, 2);
+        assert!(happy.reachability_modes_agree());
+
+        // Same-time path: only non-strict journeys chain both contacts
+        let mut chained = TemporalGraph::new();
+        chained.add_edge(0, 1, 1);
+        chained.add_edge(1, 2, 1);
+        assert!(!chained.reachability_modes_agree());
+    }
+
+    #[test]
+    fn test_to_proper_preserves_reachability() {
+        // Same-time contacts 0-1 and 0-2, but 1 and 2 talk directly before
+        // and after, so any order of the two shared contacts is harmless
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 2, 1);
+        g.add_edge(1, 2, 0);
+        g.add_edge(1, 2, 2);
+
+        let proper = g.to_proper(true).unwrap().unwrap();
+        assert!(proper.is_proper());
+        assert_eq!(proper.contact_count(), g.contact_count());
+        assert_eq!(
+            proper.reachability_graph(true).arcs,
+            g.reachability_graph(true).arcs
+        );
+    }
+
+    #[test]
+    fn test_exact_relabeling_beats_greedy() {
+        // Neither order of the colour classes keeps the reachable pairs,
+        // but another numbering of the contacts does
+        let mut g = TemporalGraph::new();
+        for (u, v, t) in [(1, 2, 1), (2, 3, 1), (0, 1, 3), (1, 3, 3)] {
+            g.add_edge(u, v, t);
+        }
+
+        assert!(g.to_proper_greedy(false).is_none());
+        let proper = g.to_proper(false).unwrap().unwrap();
+        assert!(proper.is_proper());
+        assert_eq!(proper.contact_count(), g.contact_count());
+        assert_eq!(
+            proper.reachability_graph(false).arcs,
+            g.reachability_graph(false).arcs
+        );
+    }
+
+    #[test]
+    fn test_to_proper_impossible_for_same_time_path() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+
+        assert!(matches!(g.to_proper(false), Ok(None)));
+    }
+
+    #[test]
+    fn test_exact_relabeling_is_size_limited() {
+        // Same-time path that no proper labeling can reproduce, plus enough
+        // contacts elsewhere to exceed the limit
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        for i in 0..MAX_EXACT_PROPER_CONTACTS {
+            g.add_edge(10, 11, i as TimeStep);
+        }
+
+        assert!(g.to_proper_greedy(false).is_none());
+        assert!(g.to_proper(false).unwrap_err().contains("limited to"));
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{TemporalGraph, VertexId};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Which journeys count when testing a temporal connected component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+    /// Journeys between members may pass through vertices outside the set.
+    Open,
+
+    /// Journeys between members must stay inside the set.
+    Closed,
+}
+
+impl TemporalGraph {
+    /// Check whether `vertices` are pairwise temporally reachable.
+    ///
+    /// For [`ComponentKind::Open`] reachability is evaluated in the whole
+    /// graph; for [`ComponentKind::Closed`] it is evaluated in the subgraph
+    /// induced by `vertices`. Maximality is not checked.
+    ///
+    /// # Parameters
+    /// - `vertices`: candidate component; unknown ids make the check fail.
+    /// - `kind`: open or closed component semantics.
+    /// - `strict`: forwarded to `reachable_from`.
+    pub fn is_temporal_component(
+        &self,
+        vertices: &HashSet<VertexId>,
+        kind: ComponentKind,
+        strict: bool,
+    ) -> bool {
+        if vertices.iter().any(|v| !self.has_vertex(*v)) {
+            return false;
+        }
+
+        match kind {
+            ComponentKind::Open => vertices.iter().all(|&s| {
+                let reachable = self.reachable_from(s, strict);
+                vertices.iter().all(|t| reachable.contains(t))
+            }),
+            ComponentKind::Closed => self
+                .induced_subgraph(vertices)
+                .is_temporally_connected(strict),
+        }
+    }
+
+    /// Find a largest temporal connected component.
+    ///
+    /// Both variants are NP-hard, so this runs an exact branch-and-bound
+    /// search meant for small graphs. Every closed component is also an open
+    /// one, and every open component is a clique of the mutual-reachability
+    /// graph (`s` reaches `t` and `t` reaches `s`), so the search branches
+    /// over the cliques of that graph. For closed components a candidate set
+    /// is additionally checked on its induced subgraph.
+    ///
+    /// Returns the component as a sorted vertex list. Among components of
+    /// the same size, the search prefers those with smaller vertex ids. An
+    /// empty graph yields an empty component.
+    ///
+    /// # Parameters
+    /// - `kind`: open or closed component semantics.
+    /// - `strict`: forwarded to `reachable_from`.
+    pub fn maximum_temporal_component(&self, kind: ComponentKind, strict: bool) -> Vec<VertexId> {
+        let vertices = self.vertices();
+
+        let reach: HashMap<VertexId, HashSet<VertexId>> = vertices
+            .par_iter()
+            .map(|&s| (s, self.reachable_from(s, strict)))
+            .collect();
+
+        // mutual[v] = vertices that reach v and are reached by v
+        let mutual: HashMap<VertexId, HashSet<VertexId>> = vertices
+            .iter()
+            .map(|&s| {
+                let peers = vertices
+                    .iter()
+                    .copied()
+                    .filter(|&t| t != s && reach[&s].contains(&t) && reach[&t].contains(&s))
+                    .collect();
+                (s, peers)
+            })
+            .collect();
+
+        let mut search = ComponentSearch {
+            graph: self,
+            mutual: &mutual,
+            kind,
+            strict,
+            best: Vec::new(),
+        };
+        search.branch(Vec::new(), vertices);
+
+        let mut best = search.best;
+        best.sort_unstable();
+        best
+    }
+}
+
+/// Branch-and-bound state for `maximum_temporal_component`.
+struct ComponentSearch<'a> {
+    graph: &'a TemporalGraph,
+    mutual: &'a HashMap<VertexId, HashSet<VertexId>>,
+    kind: ComponentKind,
+    strict: bool,
+    best: Vec<VertexId>,
+}
+
+impl ComponentSearch<'_> {
+    /// Explore components containing all of `chosen` and a subset of
+    /// `candidates`, which are mutually reachable with every chosen vertex.
+    fn branch(&mut self, chosen: Vec<VertexId>, mut candidates: Vec<VertexId>) {
+        // Bound: even taking every candidate cannot beat the incumbent
+        if chosen.len() + candidates.len() <= self.best.len() {
+            return;
+        }
+
+        // If the whole remaining set is a component it is the best in this branch
+        let mut all: Vec<VertexId> = chosen.clone();
+        all.extend(candidates.iter().copied());
+        if self.accepts(&all) {
+            self.best = all;
+            return;
+        }
+
+        // Branch on the first candidate: include it, then exclude it
+        let Some(v) = candidates.first().copied() else {
+            return;
+        };
+        candidates.remove(0);
+
+        let neighbours = &self.mutual[&v];
+        let with_v: Vec<VertexId> = candidates
+            .iter()
+            .copied()
+            .filter(|c| neighbours.contains(c))
+            .collect();
+        let mut chosen_with_v = chosen.clone();
+        chosen_with_v.push(v);
+        self.branch(chosen_with_v, with_v);
+
+        self.branch(chosen, candidates);
+    }
+
+    fn accepts(&self, set: &[VertexId]) -> bool {
+        // Pairwise mutual reachability is exactly the open condition
+        let is_clique = set
+            .iter()
+            .enumerate()
+            .all(|(i, a)| set[i + 1..].iter().all(|b| self.mutual[a].contains(b)));
+
+        if !is_clique {
+            return false;
+        }
+
+        match self.kind {
+            ComponentKind::Open => true,
+            ComponentKind::Closed => {
+                let members: HashSet<VertexId> = set.iter().copied().collect();
+                self.graph
+                    .induced_subgraph(&members)
+                    .is_temporally_connected(self.strict)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vertices 0 and 1 reach each other only through vertex 3:
+    /// 0 -[1]- 3 -[2]- 1 and 1 -[3]- 3 -[4]- 0, plus edges {0,2} and {1,2}
+    /// both labelled 5.
+    fn open_but_not_closed() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 3, 1);
+        g.add_edge(3, 1, 2);
+        g.add_edge(1, 3, 3);
+        g.add_edge(3, 0, 4);
+        g.add_edge(0, 2, 5);
+        g.add_edge(1, 2, 5);
+        g
+    }
+
+    #[test]
+    fn test_connected_graph_is_its_own_component() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 2);
+
+        let all: HashSet<VertexId> = [0, 1].into_iter().collect();
+        assert!(g.is_temporal_component(&all, ComponentKind::Open, true));
+        assert!(g.is_temporal_component(&all, ComponentKind::Closed, true));
+        assert_eq!(
+            g.maximum_temporal_component(ComponentKind::Closed, true),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_open_component_may_use_outside_vertices() {
+        let g = open_but_not_closed();
+        let pair: HashSet<VertexId> = [0, 1].into_iter().collect();
+
+        // 0 and 1 only reach each other through 3
+        assert!(g.is_temporal_component(&pair, ComponentKind::Open, true));
+        assert!(!g.is_temporal_component(&pair, ComponentKind::Closed, true));
+    }
+
+    #[test]
+    fn test_closed_component_never_larger_than_open() {
+        let g = open_but_not_closed();
+        let open = g.maximum_temporal_component(ComponentKind::Open, true);
+        let closed = g.maximum_temporal_component(ComponentKind::Closed, true);
+
+        assert!(closed.len() <= open.len());
+
+        let open_set: HashSet<VertexId> = open.iter().copied().collect();
+        let closed_set: HashSet<VertexId> = closed.iter().copied().collect();
+        assert!(g.is_temporal_component(&open_set, ComponentKind::Open, true));
+        assert!(g.is_temporal_component(&closed_set, ComponentKind::Closed, true));
+    }
+
+    #[test]
+    fn test_one_way_path_component_excludes_unreachable_pair() {
+        // 0 -[1]- 1 -[3]- 2 (see connectivity tests): 2 cannot reach 0
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 3);
+
+        let best = g.maximum_temporal_component(ComponentKind::Open, true);
+        assert_eq!(best.len(), 2);
+        assert!(!(best.contains(&0) && best.contains(&2)));
+    }
+
+    #[test]
+    fn test_empty_graph_component() {
+        let g = TemporalGraph::new();
+        assert!(
+            g.maximum_temporal_component(ComponentKind::Open, true)
+                .is_empty()
+        );
+    }
+}
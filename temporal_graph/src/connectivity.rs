@@ -1,5 +1,6 @@
 use crate::{TemporalGraph, TimeStep, VertexId};
-use std::collections::{HashMap, HashSet, VecDeque};
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 
 /// Result of a temporal path search between two vertices.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -10,6 +11,91 @@ pub struct TemporalPathResult {
     pub earliest_arrival: Option<TimeStep>,
 }
 
+/// Static digraph of temporal reachability.
+///
+/// There is an arc `s -> t` for every ordered pair of distinct vertices
+/// joined by a time-respecting journey. The graph is temporally connected
+/// exactly when this digraph is complete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachabilityGraph {
+    /// All vertices of the temporal graph, sorted.
+    pub vertices: Vec<VertexId>,
+    /// Reachability arcs `(source, target)`, excluding self-loops.
+    pub arcs: BTreeSet<(VertexId, VertexId)>,
+    /// Whether journeys were required to use strictly increasing timestamps.
+    pub strict: bool,
+}
+
+impl ReachabilityGraph {
+    /// Check whether `target` is reachable from `source`
+    pub fn has_arc(&self, source: VertexId, target: VertexId) -> bool {
+        self.arcs.contains(&(source, target))
+    }
+
+    /// Get the number of arcs
+    pub fn arc_count(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Get the ordered pairs of distinct vertices that are not reachable,
+    /// i.e. the arcs of the complete digraph missing from this one
+    pub fn missing_arcs(&self) -> Vec<(VertexId, VertexId)> {
+        let mut missing = Vec::new();
+        for &s in &self.vertices {
+            for &t in &self.vertices {
+                if s != t && !self.has_arc(s, t) {
+                    missing.push((s, t));
+                }
+            }
+        }
+        missing
+    }
+
+    /// Check whether every ordered pair of distinct vertices is an arc
+    pub fn is_complete(&self) -> bool {
+        let n = self.vertices.len();
+        self.arcs.len() == n * n.saturating_sub(1)
+    }
+}
+
+/// Restrictions on the journeys accepted by the constrained queries.
+///
+/// The default accepts every time-respecting journey.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JourneyConstraints {
+    /// Maximum time spent at an intermediate vertex between arriving and
+    /// leaving again (δ). The source may wait arbitrarily long before the
+    /// first hop.
+    pub max_wait: Option<TimeStep>,
+
+    /// Maximum number of contacts in the journey.
+    pub max_hops: Option<usize>,
+}
+
+impl JourneyConstraints {
+    /// Create constraints that accept every journey
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder method: set the maximum waiting time at intermediate vertices
+    ///
+    /// # Panics
+    /// If `delta` is negative, which would reject every journey with more
+    /// than one hop.
+    pub fn with_max_wait(mut self, delta: TimeStep) -> Self {
+        assert!(delta >= 0, "max_wait must be non-negative, got {}", delta);
+        self.max_wait = Some(delta);
+        self
+    }
+
+    /// Builder method: set the maximum number of hops
+    pub fn with_max_hops(mut self, hops: usize) -> Self {
+        self.max_hops = Some(hops);
+        self
+    }
+}
+
 impl TemporalGraph {
     /// Check whether there is a time-respecting path from `source` to `target`.
     ///
@@ -300,6 +386,321 @@ impl TemporalGraph {
 
         best_arrival
     }
+
+    /// Compute earliest arrival times for journeys leaving `source` no
+    /// earlier than `departure`.
+    ///
+    /// Same result format as `earliest_arrival_times`, except that the first
+    /// hop must use a timestamp `>= departure`. Passing `TimeStep::MIN`
+    /// removes the constraint. Contacts are swept once in time order, which
+    /// makes this the cheaper option when it is called once per departure
+    /// time.
+    ///
+    /// # Parameters
+    /// - `source`: starting vertex
+    /// - `departure`: earliest allowed timestamp for the first hop
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn earliest_arrival_times_from(
+        &self,
+        source: VertexId,
+        departure: TimeStep,
+        strict: bool,
+    ) -> HashMap<VertexId, TimeStep> {
+        self.foremost_tree_from(source, departure, strict)
+            .into_iter()
+            .map(|(v, (t, _))| (v, t))
+            .collect()
+    }
+
+    /// Sweep behind `earliest_arrival_times_from`, also recording for each
+    /// reached vertex the neighbour it was first reached from. Following
+    /// these predecessors back to `source` gives a foremost journey.
+    pub(crate) fn foremost_tree_from(
+        &self,
+        source: VertexId,
+        departure: TimeStep,
+        strict: bool,
+    ) -> HashMap<VertexId, (TimeStep, VertexId)> {
+        let mut arrival: HashMap<VertexId, (TimeStep, VertexId)> = HashMap::new();
+
+        if !self.has_vertex(source) {
+            return arrival;
+        }
+
+        let contacts = self.contacts();
+        let can_leave =
+            |arrival: &HashMap<VertexId, (TimeStep, VertexId)>, x: VertexId, t: TimeStep| {
+                if x == source {
+                    t >= departure
+                } else {
+                    match arrival.get(&x) {
+                        Some(&(a, _)) if strict => a < t,
+                        Some(&(a, _)) => a <= t,
+                        None => false,
+                    }
+                }
+            };
+
+        let mut start = 0;
+        while start < contacts.len() {
+            let t = contacts[start].2;
+            let mut end = start;
+            while end < contacts.len() && contacts[end].2 == t {
+                end += 1;
+            }
+
+            // Non-strict journeys may chain several contacts of the same
+            // timestamp, so repeat the group until nothing improves
+            loop {
+                let mut changed = false;
+                for &(u, v, _) in &contacts[start..end] {
+                    for (a, b) in [(u, v), (v, u)] {
+                        if b != source && !arrival.contains_key(&b) && can_leave(&arrival, a, t) {
+                            arrival.insert(b, (t, a));
+                            changed = true;
+                        }
+                    }
+                }
+                if strict || !changed {
+                    break;
+                }
+            }
+
+            start = end;
+        }
+
+        arrival
+    }
+
+    /// Build the reachability digraph (transitive closure) of the graph.
+    ///
+    /// Runs `reachable_from` once per vertex, in parallel.
+    ///
+    /// # Parameters
+    /// - `strict`: forwarded to `reachable_from`.
+    pub fn reachability_graph(&self, strict: bool) -> ReachabilityGraph {
+        let vertices = self.vertices();
+
+        let arcs: BTreeSet<(VertexId, VertexId)> = vertices
+            .par_iter()
+            .flat_map_iter(|&s| {
+                self.reachable_from(s, strict)
+                    .into_iter()
+                    .filter(move |&t| t != s)
+                    .map(move |t| (s, t))
+            })
+            .collect();
+
+        ReachabilityGraph {
+            vertices,
+            arcs,
+            strict,
+        }
+    }
+
+    /// Return every vertex with a journey into `target`, together with its
+    /// latest possible departure time.
+    ///
+    /// This is the backward counterpart of `earliest_arrival_times`: for each
+    /// source `s` in the map, the value is the largest timestamp at which `s`
+    /// can take the first hop of a journey reaching `target`, optionally
+    /// arriving no later than `deadline`. The target itself is not included.
+    ///
+    /// Contacts are swept once in decreasing time order, keeping for each
+    /// vertex the latest time at which it can still leave towards `target`.
+    ///
+    /// # Parameters
+    /// - `target`: destination vertex
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps, as in
+    ///   the forward queries.
+    /// - `deadline`: latest allowed arrival at `target`, if any.
+    pub fn reaching_to(
+        &self,
+        target: VertexId,
+        strict: bool,
+        deadline: Option<TimeStep>,
+    ) -> HashMap<VertexId, TimeStep> {
+        let mut departure: HashMap<VertexId, TimeStep> = HashMap::new();
+
+        if !self.has_vertex(target) {
+            return departure;
+        }
+
+        let deadline = deadline.unwrap_or(TimeStep::MAX);
+        let contacts = self.contacts();
+
+        // Whether a hop into `y` at time `t` can still be continued to `target`
+        let can_continue = |departure: &HashMap<VertexId, TimeStep>, y: VertexId, t: TimeStep| {
+            if y == target {
+                t <= deadline
+            } else {
+                match departure.get(&y) {
+                    Some(&next) if strict => t < next,
+                    Some(&next) => t <= next,
+                    None => false,
+                }
+            }
+        };
+
+        let mut end = contacts.len();
+        while end > 0 {
+            let t = contacts[end - 1].2;
+            let mut start = end;
+            while start > 0 && contacts[start - 1].2 == t {
+                start -= 1;
+            }
+
+            // Non-strict journeys may chain contacts sharing a timestamp
+            loop {
+                let mut changed = false;
+                for &(u, v, _) in &contacts[start..end] {
+                    for (a, b) in [(u, v), (v, u)] {
+                        if a != target
+                            && !departure.contains_key(&a)
+                            && can_continue(&departure, b, t)
+                        {
+                            departure.insert(a, t);
+                            changed = true;
+                        }
+                    }
+                }
+                if strict || !changed {
+                    break;
+                }
+            }
+
+            end = start;
+        }
+
+        departure
+    }
+
+    /// Compute earliest arrival times under waiting-time and hop constraints.
+    ///
+    /// Same result format as `earliest_arrival_times`. Under a waiting bound
+    /// an earlier arrival no longer dominates a later one, so the BFS pruning
+    /// used there does not apply. Instead, contacts are swept in time order
+    /// while keeping, for every vertex, each possible arrival time together
+    /// with the fewest hops needed to achieve it.
+    ///
+    /// # Parameters
+    /// - `source`: starting vertex
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    /// - `constraints`: maximum waiting time and/or number of hops.
+    pub fn earliest_arrival_times_constrained(
+        &self,
+        source: VertexId,
+        strict: bool,
+        constraints: &JourneyConstraints,
+    ) -> HashMap<VertexId, TimeStep> {
+        self.constrained_states(source, strict, constraints)
+            .into_iter()
+            .filter(|(v, _)| *v != source)
+            .filter_map(|(v, states)| states.keys().next().map(|&t| (v, t)))
+            .collect()
+    }
+
+    /// Return the set of vertices reachable from `source` by journeys that
+    /// satisfy `constraints`.
+    ///
+    /// The source itself is always included, as in `reachable_from`.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    /// - `constraints`: maximum waiting time and/or number of hops.
+    pub fn reachable_from_constrained(
+        &self,
+        source: VertexId,
+        strict: bool,
+        constraints: &JourneyConstraints,
+    ) -> HashSet<VertexId> {
+        if !self.has_vertex(source) {
+            return HashSet::new();
+        }
+
+        let mut reachable: HashSet<VertexId> = self
+            .constrained_states(source, strict, constraints)
+            .into_keys()
+            .collect();
+        reachable.insert(source);
+        reachable
+    }
+
+    /// Sweep contacts in time order and collect, per vertex, every arrival
+    /// time reachable under `constraints` with its minimum hop count
+    fn constrained_states(
+        &self,
+        source: VertexId,
+        strict: bool,
+        constraints: &JourneyConstraints,
+    ) -> HashMap<VertexId, BTreeMap<TimeStep, usize>> {
+        let mut states: HashMap<VertexId, BTreeMap<TimeStep, usize>> = HashMap::new();
+
+        if !self.has_vertex(source) {
+            return states;
+        }
+
+        let max_hops = constraints.max_hops.unwrap_or(usize::MAX);
+        if max_hops == 0 {
+            return states;
+        }
+
+        // Fewest hops of a journey that sits at `x` and may leave at time `t`
+        let fewest_hops =
+            |states: &HashMap<VertexId, BTreeMap<TimeStep, usize>>, x: VertexId, t: TimeStep| {
+                let mut best = if x == source { Some(0) } else { None };
+                if let Some(arrivals) = states.get(&x) {
+                    let earliest = constraints
+                        .max_wait
+                        .map(|delta| t.saturating_sub(delta))
+                        .unwrap_or(TimeStep::MIN);
+                    let window = if strict {
+                        arrivals.range(earliest..t)
+                    } else {
+                        arrivals.range(earliest..=t)
+                    };
+                    for (_, &hops) in window {
+                        best = Some(best.map_or(hops, |b: usize| b.min(hops)));
+                    }
+                }
+                best.filter(|&hops| hops < max_hops)
+            };
+
+        let contacts = self.contacts();
+        let mut start = 0;
+        while start < contacts.len() {
+            let t = contacts[start].2;
+            let mut end = start;
+            while end < contacts.len() && contacts[end].2 == t {
+                end += 1;
+            }
+
+            // As in `earliest_arrival_times_from`, non-strict journeys may
+            // chain contacts sharing a timestamp
+            loop {
+                let mut changed = false;
+                for &(u, v, _) in &contacts[start..end] {
+                    for (a, b) in [(u, v), (v, u)] {
+                        let Some(hops) = fewest_hops(&states, a, t) else {
+                            continue;
+                        };
+                        let slot = states.entry(b).or_default().entry(t).or_insert(usize::MAX);
+                        if hops + 1 < *slot {
+                            *slot = hops + 1;
+                            changed = true;
+                        }
+                    }
+                }
+                if strict || !changed {
+                    break;
+                }
+            }
+
+            start = end;
+        }
+
+        states
+    }
 }
 
 #[cfg(test)]
@@ -413,8 +814,8 @@ mod tests {
         // (would need t > 3 on edge {0,1}, only t=1 available).
         let g = one_way_graph();
         let r = g.reachable_from(2, true);
-        assert!(r.contains(&2));  // source itself
-        assert!(r.contains(&1));  // reachable via first hop t=3
+        assert!(r.contains(&2)); // source itself
+        assert!(r.contains(&1)); // reachable via first hop t=3
         assert!(!r.contains(&0)); // blocked: no t > 3 on {0,1}
         assert_eq!(r.len(), 2);
     }
@@ -429,6 +830,149 @@ mod tests {
         assert!(!arrivals.contains_key(&0)); // source excluded
     }
 
+    #[test]
+    fn test_earliest_arrival_times_from_departure() {
+        let g = path_graph();
+        // Unconstrained sweep agrees with the BFS
+        assert_eq!(
+            g.earliest_arrival_times_from(0, TimeStep::MIN, true),
+            g.earliest_arrival_times(0, true)
+        );
+        // Leaving 1 at t >= 2 forbids the hop back to 0 at t=1
+        let arrivals = g.earliest_arrival_times_from(1, 2, true);
+        assert_eq!(arrivals.get(&2), Some(&2));
+        assert_eq!(arrivals.get(&3), Some(&3));
+        assert!(!arrivals.contains_key(&0));
+    }
+
+    #[test]
+    fn test_unconstrained_queries_match_bfs() {
+        let g = path_graph();
+        let none = JourneyConstraints::new();
+        assert_eq!(
+            g.earliest_arrival_times_constrained(0, true, &none),
+            g.earliest_arrival_times(0, true)
+        );
+        assert_eq!(
+            g.reachable_from_constrained(2, true, &none),
+            g.reachable_from(2, true)
+        );
+    }
+
+    #[test]
+    fn test_max_wait_blocks_long_layover() {
+        // 0 -[1]- 1 -[5]- 2: the journey waits 4 units at vertex 1
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 5);
+
+        let patient = JourneyConstraints::new().with_max_wait(4);
+        assert!(g.reachable_from_constrained(0, true, &patient).contains(&2));
+
+        let hasty = JourneyConstraints::new().with_max_wait(3);
+        assert!(!g.reachable_from_constrained(0, true, &hasty).contains(&2));
+        // The source itself may wait before leaving
+        assert!(g.reachable_from_constrained(1, true, &hasty).contains(&2));
+    }
+
+    #[test]
+    #[should_panic(expected = "max_wait must be non-negative")]
+    fn test_negative_max_wait_is_rejected() {
+        JourneyConstraints::new().with_max_wait(-1);
+    }
+
+    #[test]
+    fn test_max_wait_prefers_later_arrival() {
+        // Arriving at 1 at t=1 is too early for the hop at t=10, but the
+        // later arrival at t=8 fits: earliest arrival does not dominate.
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 8);
+        g.add_edge(1, 2, 10);
+
+        let constraints = JourneyConstraints::new().with_max_wait(2);
+        let arrivals = g.earliest_arrival_times_constrained(0, true, &constraints);
+        assert_eq!(arrivals.get(&1), Some(&1));
+        assert_eq!(arrivals.get(&2), Some(&10));
+    }
+
+    #[test]
+    fn test_max_hops() {
+        let g = path_graph();
+        let two_hops = JourneyConstraints::new().with_max_hops(2);
+        let reachable = g.reachable_from_constrained(0, true, &two_hops);
+        assert!(reachable.contains(&2));
+        assert!(!reachable.contains(&3));
+
+        let arrivals = g.earliest_arrival_times_constrained(0, true, &two_hops);
+        assert_eq!(arrivals.get(&2), Some(&2));
+        assert!(!arrivals.contains_key(&3));
+    }
+
+    #[test]
+    fn test_reaching_to_matches_forward_reachability() {
+        for g in [path_graph(), one_way_graph()] {
+            for strict in [true, false] {
+                for target in g.vertices() {
+                    let backward: HashSet<VertexId> =
+                        g.reaching_to(target, strict, None).into_keys().collect();
+                    let forward: HashSet<VertexId> = g
+                        .vertices()
+                        .into_iter()
+                        .filter(|&s| s != target && g.reachable_from(s, strict).contains(&target))
+                        .collect();
+                    assert_eq!(backward, forward);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_reaching_to_latest_departure_and_deadline() {
+        // 0 -[1,4]- 1 -[5]- 2
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 4);
+        g.add_edge(1, 2, 5);
+
+        let departures = g.reaching_to(2, true, None);
+        assert_eq!(departures.get(&0), Some(&4));
+        assert_eq!(departures.get(&1), Some(&5));
+        assert!(!departures.contains_key(&2));
+
+        // Nothing arrives at 2 before t=5
+        assert!(g.reaching_to(2, true, Some(4)).is_empty());
+        // 0 can still reach 1 by t=3 using the first label only
+        assert_eq!(g.reaching_to(1, true, Some(3)).get(&0), Some(&1));
+    }
+
+    #[test]
+    fn test_reaching_to_strict_vs_nonstrict() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 2, 5);
+
+        assert!(!g.reaching_to(2, true, None).contains_key(&0));
+        assert_eq!(g.reaching_to(2, false, None).get(&0), Some(&5));
+    }
+
+    #[test]
+    fn test_reachability_graph() {
+        let g = one_way_graph();
+        let closure = g.reachability_graph(true);
+
+        assert!(closure.has_arc(0, 2));
+        assert!(closure.has_arc(2, 1));
+        assert!(!closure.has_arc(2, 0));
+        assert_eq!(closure.arc_count(), 5);
+        assert_eq!(closure.missing_arcs(), vec![(2, 0)]);
+        assert!(!closure.is_complete());
+
+        let mut pair = TemporalGraph::new();
+        pair.add_edge(0, 1, 1);
+        assert!(pair.reachability_graph(true).is_complete());
+    }
+
     #[test]
     fn test_disconnected_graph() {
         // Two separate components with no path between them
@@ -0,0 +1,263 @@
+use crate::{ReachabilityGraph, TemporalGraph, TimeStep, VertexId};
+use rayon::prelude::*;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Temporal graph with a reachability matrix kept up to date across contact
+/// insertions and deletions.
+///
+/// Contacts must be added and removed through this wrapper so that the
+/// matrix stays in sync. An inserted contact `(u, v, t)` only creates pairs
+/// `(a, b)` where `a` reaches `u` in time to use it and `b` is reachable
+/// from `v` afterwards, which takes two sweeps instead of one per vertex. A
+/// removed contact only affects the sources that could reach one of its
+/// endpoints before `t`; only those rows are recomputed.
+pub struct DynamicReachability {
+    graph: TemporalGraph,
+    strict: bool,
+    // reach[s] = reachable_from(s), including s itself
+    reach: HashMap<VertexId, HashSet<VertexId>>,
+    verify: bool,
+}
+
+impl DynamicReachability {
+    /// Take ownership of `graph` and compute its initial reachability
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn new(graph: TemporalGraph, strict: bool) -> Self {
+        let reach = graph
+            .vertices()
+            .par_iter()
+            .map(|&s| (s, graph.reachable_from(s, strict)))
+            .collect();
+
+        Self {
+            graph,
+            strict,
+            reach,
+            verify: false,
+        }
+    }
+
+    /// Builder method: check the matrix against a full recomputation after
+    /// every update, panicking on the first mismatch
+    pub fn with_verification(mut self) -> Self {
+        self.verify = true;
+        self.check_against_recomputation();
+        self
+    }
+
+    /// Get the underlying graph
+    pub fn graph(&self) -> &TemporalGraph {
+        &self.graph
+    }
+
+    /// Give back the underlying graph
+    pub fn into_graph(self) -> TemporalGraph {
+        self.graph
+    }
+
+    /// Check whether `target` is reachable from `source`
+    pub fn is_reachable(&self, source: VertexId, target: VertexId) -> bool {
+        self.reach
+            .get(&source)
+            .is_some_and(|reachable| reachable.contains(&target))
+    }
+
+    /// Get the vertices reachable from `source`, including `source` itself
+    pub fn reachable_from(&self, source: VertexId) -> Option<&HashSet<VertexId>> {
+        self.reach.get(&source)
+    }
+
+    /// Check whether the graph is temporally connected
+    pub fn is_temporally_connected(&self) -> bool {
+        let n = self.graph.vertex_count();
+        self.reach.values().all(|reachable| reachable.len() == n)
+    }
+
+    /// Build the reachability digraph from the maintained matrix
+    pub fn reachability_graph(&self) -> ReachabilityGraph {
+        let arcs: BTreeSet<(VertexId, VertexId)> = self
+            .reach
+            .iter()
+            .flat_map(|(&s, reachable)| {
+                reachable
+                    .iter()
+                    .filter(move |&&t| t != s)
+                    .map(move |&t| (s, t))
+            })
+            .collect();
+
+        ReachabilityGraph {
+            vertices: self.graph.vertices(),
+            arcs,
+            strict: self.strict,
+        }
+    }
+
+    /// Add a contact and extend the reachability matrix
+    pub fn add_edge(&mut self, u: VertexId, v: VertexId, time: TimeStep) {
+        let existed = self.graph.has_edge_at_time(u, v, time);
+        self.graph.add_edge(u, v, time);
+
+        for w in [u, v] {
+            self.reach.entry(w).or_insert_with(|| HashSet::from([w]));
+        }
+
+        if !existed {
+            for (a, b) in [(u, v), (v, u)] {
+                let sources = self.sources_using(a, time);
+                let targets = self.targets_after(b, time);
+                for s in sources {
+                    self.reach
+                        .get_mut(&s)
+                        .expect("sources are vertices of the graph")
+                        .extend(targets.iter().copied());
+                }
+            }
+        }
+
+        if self.verify {
+            self.check_against_recomputation();
+        }
+    }
+
+    /// Remove a contact and shrink the reachability matrix
+    ///
+    /// Returns `false` if the contact did not exist, as
+    /// `TemporalGraph::remove_edge_timestamp` does.
+    pub fn remove_edge_timestamp(&mut self, u: VertexId, v: VertexId, time: TimeStep) -> bool {
+        if !self.graph.has_edge_at_time(u, v, time) {
+            return false;
+        }
+
+        // Only sources that can take the contact may lose targets
+        let mut affected = self.sources_using(u, time);
+        affected.extend(self.sources_using(v, time));
+
+        self.graph.remove_edge_timestamp(u, v, time);
+
+        let graph = &self.graph;
+        let strict = self.strict;
+        let rows: Vec<(VertexId, HashSet<VertexId>)> = affected
+            .into_par_iter()
+            .map(|s| (s, graph.reachable_from(s, strict)))
+            .collect();
+        self.reach.extend(rows);
+
+        if self.verify {
+            self.check_against_recomputation();
+        }
+
+        true
+    }
+
+    /// Vertices that can be at `vertex` in time to leave at `time`
+    fn sources_using(&self, vertex: VertexId, time: TimeStep) -> HashSet<VertexId> {
+        let deadline = if self.strict {
+            time.saturating_sub(1)
+        } else {
+            time
+        };
+        let mut sources: HashSet<VertexId> = self
+            .graph
+            .reaching_to(vertex, self.strict, Some(deadline))
+            .into_keys()
+            .collect();
+        sources.insert(vertex);
+        sources
+    }
+
+    /// Vertices reachable from `vertex` after arriving there at `time`
+    fn targets_after(&self, vertex: VertexId, time: TimeStep) -> HashSet<VertexId> {
+        let departure = if self.strict {
+            time.saturating_add(1)
+        } else {
+            time
+        };
+        let mut targets: HashSet<VertexId> = self
+            .graph
+            .earliest_arrival_times_from(vertex, departure, self.strict)
+            .into_keys()
+            .collect();
+        targets.insert(vertex);
+        targets
+    }
+
+    fn check_against_recomputation(&self) {
+        for s in self.graph.vertices() {
+            let expected = self.graph.reachable_from(s, self.strict);
+            let actual = self.reach.get(&s).cloned().unwrap_or_default();
+            assert_eq!(
+                actual, expected,
+                "incremental reachability from {} diverged from recomputation",
+                s
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_rows(dynamic: &DynamicReachability) -> bool {
+        let graph = dynamic.graph();
+        graph
+            .vertices()
+            .into_iter()
+            .all(|s| dynamic.reachable_from(s) == Some(&graph.reachable_from(s, dynamic.strict)))
+    }
+
+    #[test]
+    fn test_insertion_completes_connectivity() {
+        // one-way path 0 -[1]- 1 -[3]- 2: 2 cannot reach 0
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 3);
+
+        let mut dynamic = DynamicReachability::new(g, true).with_verification();
+        assert!(!dynamic.is_reachable(2, 0));
+
+        dynamic.add_edge(0, 1, 4);
+        assert!(dynamic.is_reachable(2, 0));
+        assert!(dynamic.is_temporally_connected());
+        assert!(dynamic.reachability_graph().is_complete());
+    }
+
+    #[test]
+    fn test_insertion_adds_new_vertex() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+
+        let mut dynamic = DynamicReachability::new(g, true).with_verification();
+        dynamic.add_edge(1, 2, 2);
+        assert!(dynamic.is_reachable(0, 2));
+        assert!(!dynamic.is_reachable(2, 0));
+    }
+
+    #[test]
+    fn test_removal_restores_previous_matrix() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 2);
+        g.add_edge(0, 3, 5);
+
+        for strict in [true, false] {
+            let mut dynamic = DynamicReachability::new(g.clone_graph(), strict).with_verification();
+            let before = dynamic.reachability_graph();
+
+            dynamic.add_edge(1, 3, 4);
+            assert!(dynamic.remove_edge_timestamp(1, 3, 4));
+            assert_eq!(dynamic.reachability_graph(), before);
+
+            assert!(!dynamic.remove_edge_timestamp(1, 3, 4));
+            for (u, v, t) in g.contacts() {
+                dynamic.remove_edge_timestamp(u, v, t);
+                assert!(full_rows(&dynamic));
+            }
+            assert_eq!(dynamic.reachability_graph().arc_count(), 0);
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Compartmental model of an epidemic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EpidemicModel {
+    /// Susceptible-Infected: no recovery.
+    SI,
+
+    /// Susceptible-Infected-Recovered: recovered vertices are immune.
+    SIR,
+
+    /// Susceptible-Infected-Susceptible: recovered vertices can be infected
+    /// again.
+    SIS,
+}
+
+/// Configuration for `simulate_epidemic` and `simulate_epidemics`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpidemicConfig {
+    pub model: EpidemicModel,
+    /// Probability that a contact between an infected and a susceptible
+    /// vertex transmits the infection.
+    pub transmission_probability: f64,
+    /// Probability that an infected vertex recovers during each unit of
+    /// time (ignored by SI).
+    pub recovery_probability: f64,
+    /// Vertices infected before the first contact.
+    pub initially_infected: Vec<VertexId>,
+    /// Seed of the random generator; run `i` of a batch uses `seed + i`.
+    pub seed: u64,
+}
+
+impl EpidemicConfig {
+    /// Create a configuration with no recovery and seed 0
+    ///
+    /// # Panics
+    /// If `transmission_probability` is not in `[0, 1]`.
+    pub fn new(
+        model: EpidemicModel,
+        transmission_probability: f64,
+        initially_infected: Vec<VertexId>,
+    ) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&transmission_probability),
+            "transmission probability must be in [0, 1]"
+        );
+        Self {
+            model,
+            transmission_probability,
+            recovery_probability: 0.0,
+            initially_infected,
+            seed: 0,
+        }
+    }
+
+    /// Builder method: set the per-time-unit recovery probability
+    ///
+    /// # Panics
+    /// If `probability` is not in `[0, 1]`.
+    pub fn with_recovery_probability(mut self, probability: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "recovery probability must be in [0, 1]"
+        );
+        self.recovery_probability = probability;
+        self
+    }
+
+    /// Builder method: set the random seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+/// Outcome of a single epidemic run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpidemicRun {
+    /// Number of infected vertices after each distinct timestamp.
+    pub prevalence: Vec<(TimeStep, usize)>,
+    /// First infection time of every vertex infected through a contact.
+    /// Initially infected vertices are not included.
+    pub infection_times: BTreeMap<VertexId, TimeStep>,
+    /// Number of vertices infected at some point, including the initial
+    /// ones.
+    pub final_size: usize,
+}
+
+/// Outcome of a batch of independent epidemic runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpidemicBatch {
+    pub runs: Vec<EpidemicRun>,
+}
+
+impl EpidemicBatch {
+    /// Average number of infected vertices after each timestamp
+    pub fn mean_prevalence(&self) -> Vec<(TimeStep, f64)> {
+        let Some(first) = self.runs.first() else {
+            return Vec::new();
+        };
+
+        first
+            .prevalence
+            .iter()
+            .enumerate()
+            .map(|(i, &(t, _))| {
+                let total: usize = self.runs.iter().map(|run| run.prevalence[i].1).sum();
+                (t, total as f64 / self.runs.len() as f64)
+            })
+            .collect()
+    }
+
+    /// Number of runs ending with each outbreak size
+    pub fn final_size_distribution(&self) -> BTreeMap<usize, usize> {
+        let mut distribution = BTreeMap::new();
+        for run in &self.runs {
+            *distribution.entry(run.final_size).or_insert(0) += 1;
+        }
+        distribution
+    }
+
+    /// Fraction of runs in which `vertex` got infected through a contact
+    pub fn infection_frequency(&self, vertex: VertexId) -> f64 {
+        if self.runs.is_empty() {
+            return 0.0;
+        }
+        let infected = self
+            .runs
+            .iter()
+            .filter(|run| run.infection_times.contains_key(&vertex))
+            .count();
+        infected as f64 / self.runs.len() as f64
+    }
+}
+
+impl TemporalGraph {
+    /// Run one stochastic epidemic over the contacts in time order.
+    ///
+    /// At each timestamp `t`, every contact between an infected and a
+    /// susceptible vertex transmits with `transmission_probability`, based
+    /// on the states before that timestamp: a vertex infected at `t` only
+    /// becomes infectious after `t`, as along strict journeys.
+    ///
+    /// Infected vertices recover with `recovery_probability` at each unit of
+    /// time after their infection, becoming immune (SIR) or susceptible
+    /// again (SIS); a recovery at `t` takes effect after the contacts at
+    /// `t`. Between two consecutive timestamps `t_prev < t`, a vertex
+    /// therefore recovers before the contacts at `t` with probability
+    /// `1 - (1 - p)^(t - t_prev - 1)`, so long gaps without contacts are
+    /// not skipped over.
+    ///
+    /// Random draws are made in a fixed order from a ChaCha generator
+    /// seeded with `config.seed`, so runs are reproducible.
+    pub fn simulate_epidemic(&self, config: &EpidemicConfig) -> EpidemicRun {
+        self.epidemic_run(config, config.seed)
+    }
+
+    /// Run `runs` independent epidemics in parallel, with seeds
+    /// `config.seed`, `config.seed + 1`, ...
+    pub fn simulate_epidemics(&self, config: &EpidemicConfig, runs: usize) -> EpidemicBatch {
+        let runs = (0..runs as u64)
+            .into_par_iter()
+            .map(|i| self.epidemic_run(config, config.seed.wrapping_add(i)))
+            .collect();
+
+        EpidemicBatch { runs }
+    }
+
+    fn epidemic_run(&self, config: &EpidemicConfig, seed: u64) -> EpidemicRun {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let mut infected: BTreeSet<VertexId> = config
+            .initially_infected
+            .iter()
+            .copied()
+            .filter(|&v| self.has_vertex(v))
+            .collect();
+        let mut recovered: BTreeSet<VertexId> = BTreeSet::new();
+        let mut ever_infected = infected.clone();
+        let mut infection_times = BTreeMap::new();
+        let mut prevalence = Vec::new();
+
+        let contacts = self.contacts();
+        let mut previous = None;
+        let mut start = 0;
+        while start < contacts.len() {
+            let t = contacts[start].2;
+            let mut end = start;
+            while end < contacts.len() && contacts[end].2 == t {
+                end += 1;
+            }
+
+            // Time units strictly between the previous timestamp and `t`
+            let waiting = previous.map_or(0, |previous: TimeStep| t - previous - 1);
+            if waiting > 0 {
+                let probability = 1.0 - (1.0 - config.recovery_probability).powf(waiting as f64);
+                recover(
+                    config.model,
+                    probability,
+                    &mut infected,
+                    &mut recovered,
+                    &mut rng,
+                );
+            }
+            previous = Some(t);
+
+            let mut newly_infected = BTreeSet::new();
+            for &(u, v, _) in &contacts[start..end] {
+                for (a, b) in [(u, v), (v, u)] {
+                    let susceptible = !infected.contains(&b)
+                        && !recovered.contains(&b)
+                        && !newly_infected.contains(&b);
+                    if infected.contains(&a)
+                        && susceptible
+                        && rng.gen_bool(config.transmission_probability)
+                    {
+                        newly_infected.insert(b);
+                    }
+                }
+            }
+
+            recover(
+                config.model,
+                config.recovery_probability,
+                &mut infected,
+                &mut recovered,
+                &mut rng,
+            );
+            for v in newly_infected {
+                if ever_infected.insert(v) {
+                    infection_times.insert(v, t);
+                }
+                infected.insert(v);
+            }
+
+            prevalence.push((t, infected.len()));
+            start = end;
+        }
+
+        EpidemicRun {
+            prevalence,
+            infection_times,
+            final_size: ever_infected.len(),
+        }
+    }
+}
+
+/// Let every infected vertex recover with `probability`
+fn recover(
+    model: EpidemicModel,
+    probability: f64,
+    infected: &mut BTreeSet<VertexId>,
+    recovered: &mut BTreeSet<VertexId>,
+    rng: &mut ChaCha8Rng,
+) {
+    if model == EpidemicModel::SI {
+        return;
+    }
+
+    let recovering: Vec<VertexId> = infected
+        .iter()
+        .copied()
+        .filter(|_| rng.gen_bool(probability))
+        .collect();
+    for v in recovering {
+        infected.remove(&v);
+        if model == EpidemicModel::SIR {
+            recovered.insert(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, 4);
+        g.add_edge(0, 4, 5);
+        g.add_edge(1, 4, 3);
+        g
+    }
+
+    #[test]
+    fn test_certain_transmission_follows_strict_journeys() {
+        let g = sample();
+        let config = EpidemicConfig::new(EpidemicModel::SI, 1.0, vec![0]);
+        let run = g.simulate_epidemic(&config);
+
+        // The initially infected vertex itself is left out
+        let expected: BTreeMap<VertexId, TimeStep> = g
+            .earliest_arrival_times(0, true)
+            .into_iter()
+            .filter(|&(v, _)| v != 0)
+            .collect();
+        assert_eq!(run.infection_times, expected);
+        assert_eq!(run.final_size, g.reachable_from(0, true).len());
+        assert_eq!(run.prevalence.last(), Some(&(5, 5)));
+    }
+
+    #[test]
+    fn test_no_transmission() {
+        let g = sample();
+        let config =
+            EpidemicConfig::new(EpidemicModel::SIR, 0.0, vec![2]).with_recovery_probability(1.0);
+        let run = g.simulate_epidemic(&config);
+
+        assert!(run.infection_times.is_empty());
+        assert_eq!(run.final_size, 1);
+        assert!(run.prevalence.iter().all(|&(_, count)| count == 0));
+    }
+
+    #[test]
+    fn test_reproducible_batches() {
+        let g = sample();
+        let config = EpidemicConfig::new(EpidemicModel::SIS, 0.6, vec![0])
+            .with_recovery_probability(0.3)
+            .with_seed(42);
+
+        let batch = g.simulate_epidemics(&config, 50);
+        assert_eq!(batch, g.simulate_epidemics(&config, 50));
+        assert_eq!(
+            batch.runs[3],
+            g.simulate_epidemic(&config.clone().with_seed(45))
+        );
+
+        let distribution = batch.final_size_distribution();
+        assert_eq!(distribution.values().sum::<usize>(), 50);
+        assert_eq!(batch.mean_prevalence().len(), 5);
+        assert_eq!(batch.infection_frequency(0), 0.0);
+    }
+
+    #[test]
+    fn test_sir_recovery_blocks_spreading() {
+        // Every vertex stays infectious for a single timestamp: 2 is
+        // infected at time 2, too late for 2-3 at time 2, and has recovered
+        // by the time 3-4 happens
+        let g = sample();
+        let config =
+            EpidemicConfig::new(EpidemicModel::SIR, 1.0, vec![0]).with_recovery_probability(1.0);
+        let run = g.simulate_epidemic(&config);
+
+        assert_eq!(run.infection_times.get(&1), Some(&1));
+        assert_eq!(run.infection_times.get(&2), Some(&2));
+        // 1 has recovered by time 3, and 0 by time 5
+        assert_eq!(run.infection_times.get(&4), None);
+        assert_eq!(run.final_size, 3);
+    }
+
+    #[test]
+    fn test_recovery_during_gaps_between_contacts() {
+        // 1 is infected at time 1 and meets 2 right after, or 999 time
+        // units later, by which time it has recovered
+        for (t, expected) in [(2, 1.0), (1000, 0.0)] {
+            let mut g = TemporalGraph::new();
+            g.add_edge(0, 1, 1);
+            g.add_edge(1, 2, t);
+            let config = EpidemicConfig::new(EpidemicModel::SIR, 1.0, vec![0])
+                .with_recovery_probability(0.5);
+
+            let batch = g.simulate_epidemics(&config, 20);
+            assert_eq!(batch.infection_frequency(1), 1.0);
+            assert_eq!(batch.infection_frequency(2), expected, "t={t}");
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{Journey, TemporalGraph, TimeStep, VertexId};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// Time-respecting walk visiting every vertex of a temporal graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Exploration {
+    pub start: VertexId,
+    /// The walk, as a journey that may revisit vertices.
+    pub walk: Journey,
+    /// First time each vertex other than `start` is reached.
+    pub visit_times: BTreeMap<VertexId, TimeStep>,
+}
+
+impl Exploration {
+    /// Arrival time at the last newly visited vertex, `None` for a graph
+    /// with a single vertex
+    pub fn exploration_time(&self) -> Option<TimeStep> {
+        self.visit_times.values().max().copied()
+    }
+
+    /// Vertices in the order they are first visited, starting with `start`
+    pub fn visit_order(&self) -> Vec<VertexId> {
+        let mut order = vec![self.start];
+        for (_, v, _) in &self.walk.hops {
+            if !order.contains(v) {
+                order.push(*v);
+            }
+        }
+        order
+    }
+}
+
+/// State of the exploration dynamic program: visited targets and position
+type ExplorationState = (u64, VertexId);
+
+impl TemporalGraph {
+    /// Compute an exploration arriving as early as possible, by dynamic
+    /// programming over (set of visited vertices, current position).
+    ///
+    /// Any exploration can be cut at the first visit of each vertex, and
+    /// reaching the next vertex earlier never hurts since the walk may
+    /// wait. So for every state the program keeps the earliest time it can
+    /// be reached, and moves to each unvisited vertex along a foremost
+    /// journey from the current position (see
+    /// `earliest_arrival_times_from`). Vertices crossed on the way are
+    /// revisited later if needed, which costs nothing in time.
+    ///
+    /// Runs in `O(2^n * n)` foremost-journey lookups: only use this on small
+    /// graphs, and `explore_greedy` otherwise. Returns `None` if `start` is
+    /// unknown or no exploration exists.
+    ///
+    /// # Panics
+    /// If the graph has more than 64 vertices.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn explore_exact(&self, start: VertexId, strict: bool) -> Option<Exploration> {
+        if !self.has_vertex(start) {
+            return None;
+        }
+
+        let targets: Vec<VertexId> = self
+            .vertices()
+            .into_iter()
+            .filter(|&v| v != start)
+            .collect();
+        assert!(
+            targets.len() <= 64,
+            "exact exploration is limited to 64 vertices"
+        );
+        let full = if targets.len() == 64 {
+            u64::MAX
+        } else {
+            (1u64 << targets.len()) - 1
+        };
+
+        let mut trees = ForemostTrees::new(self, strict);
+
+        // layers[k]: states with k visited targets -> (arrival, parent state)
+        let mut layers: Vec<BTreeMap<ExplorationState, (TimeStep, ExplorationState)>> =
+            vec![BTreeMap::from([((0, start), (TimeStep::MIN, (0, start)))])];
+
+        for _ in 0..targets.len() {
+            let mut next: BTreeMap<ExplorationState, (TimeStep, ExplorationState)> =
+                BTreeMap::new();
+            for (&(mask, position), &(arrival, _)) in layers.last().expect("layers start non-empty")
+            {
+                let tree = trees.get(position, arrival);
+                for (j, target) in targets.iter().enumerate() {
+                    if mask & (1 << j) != 0 {
+                        continue;
+                    }
+                    let Some(&(reached, _)) = tree.get(target) else {
+                        continue;
+                    };
+                    let state = (mask | (1 << j), *target);
+                    if next.get(&state).is_none_or(|&(best, _)| reached < best) {
+                        next.insert(state, (reached, (mask, position)));
+                    }
+                }
+            }
+            if next.is_empty() {
+                return None;
+            }
+            layers.push(next);
+        }
+
+        // Earliest complete state, then walk the parents back
+        let (&last, _) = layers
+            .last()
+            .expect("layers start non-empty")
+            .iter()
+            .filter(|((mask, _), _)| *mask == full)
+            .min_by_key(|(_, (arrival, _))| *arrival)?;
+
+        let mut stops = vec![last];
+        for layer in layers.iter().skip(1).rev() {
+            let state = *stops.last().expect("stops start non-empty");
+            let (_, parent) = layer[&state];
+            stops.push(parent);
+        }
+        stops.reverse();
+
+        let mut hops = Vec::new();
+        let mut arrival = TimeStep::MIN;
+        for pair in stops.windows(2) {
+            let (from, to) = (pair[0].1, pair[1].1);
+            let leg = trees.journey(from, arrival, to);
+            arrival = leg.last().map_or(arrival, |&(_, _, t)| t);
+            hops.extend(leg);
+        }
+
+        Some(Exploration::from_walk(start, hops))
+    }
+
+    /// Compute an exploration greedily, always heading to the unvisited
+    /// vertex that can be reached the earliest from the current position.
+    ///
+    /// Each step takes one foremost-journey sweep, so this scales to large
+    /// graphs, but the result may arrive later than `explore_exact`, and it
+    /// returns `None` when the greedy walk strands itself even though an
+    /// exploration exists. Ties go to the smallest vertex.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn explore_greedy(&self, start: VertexId, strict: bool) -> Option<Exploration> {
+        if !self.has_vertex(start) {
+            return None;
+        }
+
+        let mut trees = ForemostTrees::new(self, strict);
+        let mut visited: HashSet<VertexId> = HashSet::from([start]);
+        let mut position = start;
+        let mut arrival = TimeStep::MIN;
+        let mut hops = Vec::new();
+
+        while visited.len() < self.vertex_count() {
+            let tree = trees.get(position, arrival);
+            let (&next, &(reached, _)) = tree
+                .iter()
+                .filter(|(v, _)| !visited.contains(v))
+                .min_by_key(|&(v, &(reached, _))| (reached, *v))?;
+
+            let leg = trees.journey(position, arrival, next);
+            visited.extend(leg.iter().map(|&(_, v, _)| v));
+            hops.extend(leg);
+            position = next;
+            arrival = reached;
+        }
+
+        Some(Exploration::from_walk(start, hops))
+    }
+}
+
+impl Exploration {
+    fn from_walk(start: VertexId, hops: Vec<(VertexId, VertexId, TimeStep)>) -> Self {
+        let mut visit_times = BTreeMap::new();
+        for &(_, v, t) in &hops {
+            if v != start {
+                visit_times.entry(v).or_insert(t);
+            }
+        }
+
+        Exploration {
+            start,
+            walk: Journey { hops },
+            visit_times,
+        }
+    }
+}
+
+/// Foremost-journey trees, cached by (position, arrival time there)
+struct ForemostTrees<'a> {
+    graph: &'a TemporalGraph,
+    strict: bool,
+    cache: HashMap<(VertexId, TimeStep), HashMap<VertexId, (TimeStep, VertexId)>>,
+}
+
+impl<'a> ForemostTrees<'a> {
+    fn new(graph: &'a TemporalGraph, strict: bool) -> Self {
+        Self {
+            graph,
+            strict,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Tree of foremost journeys for a walk standing at `position` since
+    /// `arrival` (`TimeStep::MIN` before the first hop)
+    fn get(
+        &mut self,
+        position: VertexId,
+        arrival: TimeStep,
+    ) -> &HashMap<VertexId, (TimeStep, VertexId)> {
+        let departure = if arrival == TimeStep::MIN || !self.strict {
+            arrival
+        } else {
+            arrival.saturating_add(1)
+        };
+        let (graph, strict) = (self.graph, self.strict);
+        self.cache
+            .entry((position, arrival))
+            .or_insert_with(|| graph.foremost_tree_from(position, departure, strict))
+    }
+
+    /// Hops of the foremost journey from `position` to `target`
+    fn journey(
+        &mut self,
+        position: VertexId,
+        arrival: TimeStep,
+        target: VertexId,
+    ) -> Vec<(VertexId, VertexId, TimeStep)> {
+        let tree = self.get(position, arrival);
+        let mut hops = Vec::new();
+        let mut current = target;
+        while current != position {
+            let (t, previous) = tree[&current];
+            hops.push((previous, current, t));
+            current = previous;
+        }
+        hops.reverse();
+        hops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Star around 0 whose leaves can only be explored in one order: 1 can
+    // come back to 0 in time for 0-2 at time 4, while 2 is stranded after
+    // the early contact at time 1.
+    fn star() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 2, 1);
+        g.add_edge(0, 1, 2);
+        g.add_edge(0, 1, 3);
+        g.add_edge(0, 2, 4);
+        g
+    }
+
+    #[test]
+    fn test_exact_exploration() {
+        let g = star();
+        let exploration = g.explore_exact(0, true).unwrap();
+
+        assert_eq!(exploration.exploration_time(), Some(4));
+        assert_eq!(exploration.visit_order(), vec![0, 1, 2]);
+        assert_eq!(exploration.walk.hops, vec![(0, 1, 2), (1, 0, 3), (0, 2, 4)]);
+        // Heading for 2 first is a dead end
+        assert!(g.explore_greedy(0, true).is_none());
+    }
+
+    #[test]
+    fn test_greedy_can_be_beaten() {
+        // Greedy takes 0-1 at time 1, then only 3 is left in reach and the
+        // walk is stuck; the path 0 - 2 - 3 - 1 explores everything
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 2, 2);
+        g.add_edge(2, 3, 3);
+        g.add_edge(3, 1, 4);
+
+        assert_eq!(g.explore_greedy(0, true), None);
+        let exact = g.explore_exact(0, true).unwrap();
+        assert_eq!(exact.exploration_time(), Some(4));
+        assert_eq!(exact.visit_order(), vec![0, 2, 3, 1]);
+    }
+
+    #[test]
+    fn test_walk_is_time_respecting() {
+        let mut g = TemporalGraph::new();
+        for (u, v, t) in [
+            (0, 1, 1),
+            (1, 2, 1),
+            (2, 3, 2),
+            (3, 0, 2),
+            (1, 3, 5),
+            (0, 2, 6),
+        ] {
+            g.add_edge(u, v, t);
+        }
+
+        for strict in [true, false] {
+            for exploration in [g.explore_exact(0, strict), g.explore_greedy(0, strict)]
+                .into_iter()
+                .flatten()
+            {
+                let hops = &exploration.walk.hops;
+                assert_eq!(hops.first().map(|h| h.0), Some(0));
+                for pair in hops.windows(2) {
+                    assert_eq!(pair[0].1, pair[1].0);
+                    assert!(if strict {
+                        pair[0].2 < pair[1].2
+                    } else {
+                        pair[0].2 <= pair[1].2
+                    });
+                }
+                assert_eq!(exploration.visit_times.len(), 3);
+            }
+        }
+
+        let exact = g.explore_exact(0, false).unwrap();
+        let greedy = g.explore_greedy(0, false).unwrap();
+        assert!(exact.exploration_time() <= greedy.exploration_time());
+    }
+
+    #[test]
+    fn test_exploration_fails_when_disconnected() {
+        let mut g = star();
+        g.add_vertex(9);
+        assert!(g.explore_exact(0, true).is_none());
+        assert!(g.explore_greedy(0, true).is_none());
+    }
+}
@@ -0,0 +1,421 @@
+use crate::{TemporalGraph, TimeExpandedArcKind, TimeStep, VertexId};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Capacities and storage rules for `maximum_temporal_flow`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemporalFlowConfig {
+    /// Capacity of contacts without an explicit one.
+    pub default_capacity: u64,
+    /// Per-contact capacities, keyed by `(min(u, v), max(u, v), t)`.
+    pub capacities: HashMap<(VertexId, VertexId, TimeStep), u64>,
+    /// Whether intermediate vertices may hold flow between contacts.
+    pub vertex_storage: bool,
+}
+
+impl TemporalFlowConfig {
+    /// Every contact gets `default_capacity`, vertices may store flow
+    pub fn new(default_capacity: u64) -> Self {
+        Self {
+            default_capacity,
+            capacities: HashMap::new(),
+            vertex_storage: true,
+        }
+    }
+
+    /// Builder method: set the capacity of contact `(u, v, t)`
+    pub fn with_capacity(
+        mut self,
+        u: VertexId,
+        v: VertexId,
+        time: TimeStep,
+        capacity: u64,
+    ) -> Self {
+        self.capacities.insert((u.min(v), u.max(v), time), capacity);
+        self
+    }
+
+    /// Builder method: forbid intermediate vertices from holding flow
+    pub fn without_storage(mut self) -> Self {
+        self.vertex_storage = false;
+        self
+    }
+
+    /// Capacity of contact `(u, v, t)`
+    pub fn capacity(&self, u: VertexId, v: VertexId, time: TimeStep) -> u64 {
+        self.capacities
+            .get(&(u.min(v), u.max(v), time))
+            .copied()
+            .unwrap_or(self.default_capacity)
+    }
+}
+
+/// Maximum flow over time between two vertices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemporalFlow {
+    /// Total amount of flow delivered to the target.
+    pub value: u64,
+    /// Flow on each used contact, keyed by direction `(from, to, t)`.
+    pub contact_flows: BTreeMap<(VertexId, VertexId, TimeStep), u64>,
+}
+
+impl TemporalFlow {
+    /// Flow sent from `from` to `to` over the contact at `time`
+    pub fn flow_on(&self, from: VertexId, to: VertexId, time: TimeStep) -> u64 {
+        self.contact_flows
+            .get(&(from, to, time))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl TemporalGraph {
+    /// Compute a maximum flow over time from `source` to `target`.
+    ///
+    /// Flow travels along contacts in time-respecting order. Each contact
+    /// carries at most its capacity, in total over both directions. The
+    /// source can release flow at any of its contacts and the target
+    /// absorbs whatever reaches it. When `vertex_storage` is disabled, flow
+    /// reaching an intermediate vertex at time `t` must leave at `t` itself,
+    /// which requires non-strict mode.
+    ///
+    /// The problem is solved as a static maximum flow (Dinic's algorithm) on
+    /// the compressed `TimeExpandedGraph`, where waiting arcs are unbounded.
+    /// Both arcs of a contact `{u, v}` at `t` are replaced by one gadget:
+    /// the departure nodes of `u` and `v` feed a single arc carrying the
+    /// capacity, which leads to both arrival nodes. Without storage, only
+    /// the source keeps its waiting arcs.
+    ///
+    /// Flow entering the gadget from `u` and leaving it back at `u` is
+    /// reported as waiting, not as contact flow. This is exact with
+    /// storage, where such flow could have waited anyway, and in non-strict
+    /// mode, where it comes back to the node it left. In strict mode without
+    /// storage it would wait one time step, and flows sharing a contact in
+    /// both directions cannot be expressed as a static flow, so that
+    /// combination is rejected.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    /// - `config`: contact capacities and storage rule.
+    pub fn maximum_temporal_flow(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+        config: &TemporalFlowConfig,
+    ) -> Result<TemporalFlow, String> {
+        if strict && !config.vertex_storage {
+            return Err("Flows without vertex storage require non-strict journeys".to_string());
+        }
+
+        let mut result = TemporalFlow {
+            value: 0,
+            contact_flows: BTreeMap::new(),
+        };
+        if source == target || !self.has_vertex(source) || !self.has_vertex(target) {
+            return Ok(result);
+        }
+
+        let expanded = self.time_expanded(strict, true);
+        let contacts = self.contacts();
+        let unbounded = contacts
+            .iter()
+            .map(|&(u, v, t)| config.capacity(u, v, t))
+            .fold(1u64, |acc, c| acc.saturating_add(c));
+
+        // Two gadget nodes per contact after the time-expanded nodes
+        let super_source = expanded.node_count() + 2 * contacts.len();
+        let super_sink = super_source + 1;
+        let mut network = FlowNetwork::new(super_sink + 1);
+
+        for arc in &expanded.arcs {
+            if arc.kind == TimeExpandedArcKind::Waiting {
+                let vertex = expanded.nodes[arc.tail].0;
+                if config.vertex_storage || vertex == source {
+                    network.add_arc(arc.tail, arc.head, unbounded);
+                }
+            }
+        }
+
+        let arrival = |t: TimeStep| if strict { t.saturating_add(1) } else { t };
+        let node = |v: VertexId, t: TimeStep| {
+            expanded
+                .node(v, t)
+                .expect("compressed graphs keep contact nodes")
+        };
+        let mut gadgets = Vec::new();
+        for (i, &(u, v, t)) in contacts.iter().enumerate() {
+            let entry = expanded.node_count() + 2 * i;
+            let exit = entry + 1;
+            let from_u = network.add_arc(node(u, t), entry, unbounded);
+            let from_v = network.add_arc(node(v, t), entry, unbounded);
+            network.add_arc(entry, exit, config.capacity(u, v, t));
+            let to_u = network.add_arc(exit, node(u, arrival(t)), unbounded);
+            let to_v = network.add_arc(exit, node(v, arrival(t)), unbounded);
+            gadgets.push(((u, v, t), [from_u, from_v, to_u, to_v]));
+        }
+
+        if let Some(&first) = expanded.nodes_of(source).first() {
+            network.add_arc(super_source, first, unbounded);
+        }
+        for node in expanded.nodes_of(target) {
+            network.add_arc(node, super_sink, unbounded);
+        }
+
+        result.value = network.max_flow(super_source, super_sink);
+        for ((u, v, t), [from_u, from_v, to_u, to_v]) in gadgets {
+            // Pair entries with exits at the other endpoint first; the rest
+            // comes back where it entered
+            let [from_u, from_v, to_u, to_v] =
+                [from_u, from_v, to_u, to_v].map(|arc| network.flow(arc));
+            for (contact, flow) in [((u, v, t), from_u.min(to_v)), ((v, u, t), from_v.min(to_u))] {
+                if flow > 0 {
+                    result.contact_flows.insert(contact, flow);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Residual network for Dinic's maximum flow algorithm
+struct FlowNetwork {
+    // arcs[i] = (head, residual capacity); arc i ^ 1 is its reverse
+    arcs: Vec<(usize, u64)>,
+    capacities: Vec<u64>,
+    outgoing: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        Self {
+            arcs: Vec::new(),
+            capacities: Vec::new(),
+            outgoing: vec![Vec::new(); nodes],
+        }
+    }
+
+    /// Add an arc and return its index
+    fn add_arc(&mut self, from: usize, to: usize, capacity: u64) -> usize {
+        let index = self.arcs.len();
+        self.arcs.push((to, capacity));
+        self.arcs.push((from, 0));
+        self.capacities.push(capacity);
+        self.capacities.push(0);
+        self.outgoing[from].push(index);
+        self.outgoing[to].push(index + 1);
+        index
+    }
+
+    /// Flow currently on arc `index`
+    fn flow(&self, index: usize) -> u64 {
+        self.capacities[index] - self.arcs[index].1
+    }
+
+    fn max_flow(&mut self, source: usize, sink: usize) -> u64 {
+        let mut total = 0u64;
+
+        while let Some(levels) = self.levels(source, sink) {
+            let mut next_arc = vec![0; self.outgoing.len()];
+            loop {
+                let pushed = self.augment(source, sink, u64::MAX, &levels, &mut next_arc);
+                if pushed == 0 {
+                    break;
+                }
+                total = total.saturating_add(pushed);
+            }
+        }
+
+        total
+    }
+
+    /// BFS levels in the residual network, `None` once the sink is cut off
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        let mut levels = vec![usize::MAX; self.outgoing.len()];
+        levels[source] = 0;
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(node) = queue.pop_front() {
+            for &arc in &self.outgoing[node] {
+                let (head, residual) = self.arcs[arc];
+                if residual > 0 && levels[head] == usize::MAX {
+                    levels[head] = levels[node] + 1;
+                    queue.push_back(head);
+                }
+            }
+        }
+
+        (levels[sink] != usize::MAX).then_some(levels)
+    }
+
+    /// Push a blocking-flow path along increasing levels
+    fn augment(
+        &mut self,
+        node: usize,
+        sink: usize,
+        limit: u64,
+        levels: &[usize],
+        next_arc: &mut [usize],
+    ) -> u64 {
+        if node == sink {
+            return limit;
+        }
+
+        while next_arc[node] < self.outgoing[node].len() {
+            let arc = self.outgoing[node][next_arc[node]];
+            let (head, residual) = self.arcs[arc];
+            if residual > 0 && levels[head] == levels[node] + 1 {
+                let pushed = self.augment(head, sink, limit.min(residual), levels, next_arc);
+                if pushed > 0 {
+                    self.arcs[arc].1 -= pushed;
+                    self.arcs[arc ^ 1].1 += pushed;
+                    return pushed;
+                }
+            }
+            next_arc[node] += 1;
+        }
+
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two routes 0 -> 1 -> 3 and 0 -> 2 -> 3, both waiting at the middle
+    fn diamond() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 3, 3);
+        g.add_edge(0, 2, 1);
+        g.add_edge(2, 3, 2);
+        g
+    }
+
+    #[test]
+    fn test_diamond_flow() {
+        let g = diamond();
+        let flow = g
+            .maximum_temporal_flow(0, 3, true, &TemporalFlowConfig::new(1))
+            .unwrap();
+
+        assert_eq!(flow.value, 2);
+        assert_eq!(flow.flow_on(0, 1, 1), 1);
+        assert_eq!(flow.flow_on(1, 3, 3), 1);
+        assert_eq!(flow.flow_on(3, 1, 3), 0);
+        assert_eq!(flow.contact_flows.len(), 4);
+    }
+
+    #[test]
+    fn test_capacities_bound_flow() {
+        let g = diamond();
+        let config = TemporalFlowConfig::new(5).with_capacity(3, 1, 3, 2);
+
+        let flow = g.maximum_temporal_flow(0, 3, true, &config).unwrap();
+        assert_eq!(flow.value, 7);
+        assert_eq!(flow.flow_on(1, 3, 3), 2);
+    }
+
+    #[test]
+    fn test_storage() {
+        // 0 -> 1 -> 2 at time 4, or waiting at 1 for 1-2 at time 6
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 4);
+        g.add_edge(1, 2, 4);
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 2, 6);
+        let config = TemporalFlowConfig::new(1);
+
+        assert_eq!(
+            g.maximum_temporal_flow(0, 2, false, &config).unwrap().value,
+            2
+        );
+        let flow = g
+            .maximum_temporal_flow(0, 2, false, &config.without_storage())
+            .unwrap();
+        assert_eq!(flow.value, 1);
+        assert_eq!(flow.flow_on(1, 2, 4), 1);
+        assert_eq!(flow.flow_on(1, 2, 6), 0);
+    }
+
+    #[test]
+    fn test_strict_without_storage_is_rejected() {
+        // Strict flow through 1 would have to wait from time 2 to time 3,
+        // which the contact 1-2 at time 2 must not hide
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(1, 3, 3);
+        let config = TemporalFlowConfig::new(1);
+
+        assert!(
+            g.maximum_temporal_flow(0, 3, true, &config.clone().without_storage())
+                .is_err()
+        );
+        let flow = g
+            .maximum_temporal_flow(0, 3, false, &config.clone().without_storage())
+            .unwrap();
+        assert_eq!(flow.value, 0);
+        assert!(flow.contact_flows.is_empty());
+
+        // With storage, waiting at 1 is allowed and 1-2 stays unused
+        let flow = g.maximum_temporal_flow(0, 3, true, &config).unwrap();
+        assert_eq!(flow.value, 1);
+        assert_eq!(
+            flow.contact_flows,
+            BTreeMap::from([((0, 1, 1), 1), ((1, 3, 3), 1)])
+        );
+    }
+
+    #[test]
+    fn test_flow_respects_time() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 2, 3);
+        let config = TemporalFlowConfig::new(1);
+        assert_eq!(
+            g.maximum_temporal_flow(0, 2, true, &config).unwrap().value,
+            0
+        );
+        assert_eq!(
+            g.maximum_temporal_flow(2, 0, true, &config).unwrap().value,
+            1
+        );
+
+        // Same-time chain only counts in non-strict mode
+        let mut chained = TemporalGraph::new();
+        chained.add_edge(0, 1, 4);
+        chained.add_edge(1, 2, 4);
+        assert_eq!(
+            chained
+                .maximum_temporal_flow(0, 2, true, &config)
+                .unwrap()
+                .value,
+            0
+        );
+        let flow = chained
+            .maximum_temporal_flow(0, 2, false, &config.without_storage())
+            .unwrap();
+        assert_eq!(flow.value, 1);
+    }
+
+    #[test]
+    fn test_directions_share_capacity() {
+        // At time 1, 0 -> 1 -> 2 -> 3 and 0 -> 2 -> 1 -> 4 -> 3 both want
+        // the contact 1-2, one in each direction
+        let mut g = TemporalGraph::new();
+        for (u, v) in [(0, 1), (0, 2), (1, 2), (2, 3), (1, 4), (4, 3)] {
+            g.add_edge(u, v, 1);
+        }
+        let config = TemporalFlowConfig::new(2)
+            .with_capacity(0, 1, 1, 1)
+            .with_capacity(0, 2, 1, 1)
+            .with_capacity(1, 2, 1, 1)
+            .without_storage();
+
+        let flow = g.maximum_temporal_flow(0, 3, false, &config).unwrap();
+        assert_eq!(flow.value, 2);
+        assert!(flow.flow_on(1, 2, 1) + flow.flow_on(2, 1, 1) <= 1);
+    }
+}
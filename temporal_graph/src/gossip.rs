@@ -0,0 +1,275 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Information spreading process simulated by `simulate_spreading`.
+///
+/// Rumours are identified by the vertex they originate from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpreadingModel {
+    /// A single rumour starting at the given vertex, passed on along
+    /// contacts.
+    Broadcast(VertexId),
+
+    /// Every vertex starts with its own rumour; rumours follow journeys, so
+    /// contacts at the same time are simultaneous (strict) or chained
+    /// (non-strict).
+    AllToAll,
+
+    /// Every vertex starts with its own rumour and each contact is a phone
+    /// call in which both endpoints exchange everything they know. Calls are
+    /// made one after the other in `contacts()` order, even when they share
+    /// a timestamp; the `strict` flag is ignored.
+    Telephone,
+}
+
+/// Who knows which rumours after all contacts at `time`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnowledgeSnapshot {
+    pub time: TimeStep,
+    pub knowledge: BTreeMap<VertexId, BTreeSet<VertexId>>,
+}
+
+/// Outcome of a spreading simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisseminationReport {
+    pub model: SpreadingModel,
+    /// Origins of the simulated rumours.
+    pub rumours: Vec<VertexId>,
+    /// Knowledge before any contact.
+    pub initial: BTreeMap<VertexId, BTreeSet<VertexId>>,
+    /// Knowledge after each distinct timestamp, in time order.
+    pub steps: Vec<KnowledgeSnapshot>,
+    /// Time at which each rumour is known by every vertex, `None` if it
+    /// never is (or needed no contact at all, on a single vertex).
+    pub completion_times: BTreeMap<VertexId, Option<TimeStep>>,
+    /// Number of contacts processed until every rumour was everywhere.
+    pub calls_to_completion: Option<usize>,
+}
+
+impl DisseminationReport {
+    /// Knowledge at the end of the simulation
+    pub fn final_knowledge(&self) -> &BTreeMap<VertexId, BTreeSet<VertexId>> {
+        self.steps
+            .last()
+            .map_or(&self.initial, |snapshot| &snapshot.knowledge)
+    }
+
+    /// Check whether every vertex ends up knowing every rumour
+    pub fn is_complete(&self) -> bool {
+        self.final_knowledge()
+            .values()
+            .all(|known| known.len() == self.rumours.len())
+    }
+
+    /// Time at which the last rumour reached every vertex
+    pub fn completion_time(&self) -> Option<TimeStep> {
+        if !self.is_complete() {
+            return None;
+        }
+        self.completion_times.values().flatten().max().copied()
+    }
+}
+
+impl TemporalGraph {
+    /// Simulate information spreading over the contacts in time order.
+    ///
+    /// Records who knows which rumours after each timestamp, when each
+    /// rumour reaches every vertex, and how many contacts were needed for
+    /// all of them to. Under `SpreadingModel::Telephone` the latter is the
+    /// number of calls of the classical gossip problem, which is at least
+    /// `2n - 4` for `n >= 4` vertices.
+    ///
+    /// # Parameters
+    /// - `model`: which rumours exist and how contacts exchange them.
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps along
+    ///   the spreading routes.
+    pub fn simulate_spreading(&self, model: SpreadingModel, strict: bool) -> DisseminationReport {
+        let vertices = self.vertices();
+        let rumours: Vec<VertexId> = match model {
+            SpreadingModel::Broadcast(source) if self.has_vertex(source) => vec![source],
+            SpreadingModel::Broadcast(_) => Vec::new(),
+            SpreadingModel::AllToAll | SpreadingModel::Telephone => vertices.clone(),
+        };
+
+        let mut knowledge: BTreeMap<VertexId, BTreeSet<VertexId>> =
+            vertices.iter().map(|&v| (v, BTreeSet::new())).collect();
+        for &r in &rumours {
+            knowledge.entry(r).or_default().insert(r);
+        }
+
+        let mut report = DisseminationReport {
+            model,
+            rumours: rumours.clone(),
+            initial: knowledge.clone(),
+            steps: Vec::new(),
+            completion_times: rumours.iter().map(|&r| (r, None)).collect(),
+            calls_to_completion: None,
+        };
+
+        let contacts = self.contacts();
+        let mut pending: BTreeSet<VertexId> = rumours
+            .iter()
+            .copied()
+            .filter(|r| knowledge.values().any(|known| !known.contains(r)))
+            .collect();
+        let all_pending = !pending.is_empty();
+        let mut calls = 0;
+
+        let mut start = 0;
+        while start < contacts.len() {
+            let t = contacts[start].2;
+            let mut end = start;
+            while end < contacts.len() && contacts[end].2 == t {
+                end += 1;
+            }
+            let group = &contacts[start..end];
+
+            match model {
+                SpreadingModel::Telephone => {
+                    for &(u, v, _) in group {
+                        let shared: BTreeSet<VertexId> =
+                            knowledge[&u].union(&knowledge[&v]).copied().collect();
+                        knowledge.insert(u, shared.clone());
+                        knowledge.insert(v, shared);
+                        calls += 1;
+                        complete_rumours(&knowledge, &mut pending, &mut report, t, calls);
+                    }
+                }
+                _ if strict => {
+                    let before = knowledge.clone();
+                    for &(u, v, _) in group {
+                        exchange(&mut knowledge, &before, u, v);
+                    }
+                }
+                _ => loop {
+                    let before = knowledge.clone();
+                    for &(u, v, _) in group {
+                        let current = knowledge.clone();
+                        exchange(&mut knowledge, &current, u, v);
+                    }
+                    if knowledge == before {
+                        break;
+                    }
+                },
+            }
+
+            calls = end;
+            complete_rumours(&knowledge, &mut pending, &mut report, t, calls);
+            report.steps.push(KnowledgeSnapshot {
+                time: t,
+                knowledge: knowledge.clone(),
+            });
+            start = end;
+        }
+
+        if !all_pending {
+            report.calls_to_completion = Some(0);
+        }
+
+        report
+    }
+}
+
+/// Pass what `u` and `v` knew in `before` to each other
+fn exchange(
+    knowledge: &mut BTreeMap<VertexId, BTreeSet<VertexId>>,
+    before: &BTreeMap<VertexId, BTreeSet<VertexId>>,
+    u: VertexId,
+    v: VertexId,
+) {
+    for (a, b) in [(u, v), (v, u)] {
+        let learned = before[&b].clone();
+        knowledge
+            .get_mut(&a)
+            .expect("contact endpoints are vertices")
+            .extend(learned);
+    }
+}
+
+/// Record the completion of every pending rumour now known everywhere
+fn complete_rumours(
+    knowledge: &BTreeMap<VertexId, BTreeSet<VertexId>>,
+    pending: &mut BTreeSet<VertexId>,
+    report: &mut DisseminationReport,
+    time: TimeStep,
+    calls: usize,
+) {
+    if pending.is_empty() {
+        return;
+    }
+
+    pending.retain(|r| {
+        let done = knowledge.values().all(|known| known.contains(r));
+        if done {
+            report.completion_times.insert(*r, Some(time));
+        }
+        !done
+    });
+
+    if pending.is_empty() {
+        report.calls_to_completion = Some(calls);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Classical optimal gossip on four people: 0-1, 2-3, 0-2, 1-3
+    fn four_calls() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(0, 2, 3);
+        g.add_edge(1, 3, 4);
+        g
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let g = four_calls();
+        let report = g.simulate_spreading(SpreadingModel::Broadcast(0), true);
+
+        assert_eq!(report.rumours, vec![0]);
+        assert_eq!(report.steps.len(), 4);
+        // 3 learns at time 4 from 1
+        assert!(!report.steps[2].knowledge[&3].contains(&0));
+        assert_eq!(report.completion_times[&0], Some(4));
+        assert_eq!(report.completion_time(), Some(4));
+        assert_eq!(report.calls_to_completion, Some(4));
+    }
+
+    #[test]
+    fn test_telephone_meets_gossip_bound() {
+        let g = four_calls();
+        let report = g.simulate_spreading(SpreadingModel::Telephone, true);
+
+        assert!(report.is_complete());
+        assert_eq!(report.calls_to_completion, Some(2 * 4 - 4));
+        // Rumours 2 and 3 are complete as soon as 0 and 1 hear them
+        assert_eq!(report.completion_times[&2], Some(4));
+        assert_eq!(report.completion_times[&0], Some(4));
+    }
+
+    #[test]
+    fn test_same_time_contacts() {
+        // Path 0 - 1 - 2, all at time 1
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+
+        let strict = g.simulate_spreading(SpreadingModel::AllToAll, true);
+        assert!(!strict.is_complete());
+        assert_eq!(strict.completion_times[&1], Some(1));
+        assert_eq!(strict.completion_times[&0], None);
+
+        let chained = g.simulate_spreading(SpreadingModel::AllToAll, false);
+        assert!(chained.is_complete());
+        assert_eq!(chained.completion_time(), Some(1));
+
+        // Sequential calls: 0-1 then 1-2 carries 0's rumour but not back
+        let telephone = g.simulate_spreading(SpreadingModel::Telephone, true);
+        assert_eq!(telephone.final_knowledge()[&2].len(), 3);
+        assert_eq!(telephone.final_knowledge()[&0].len(), 2);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use num_bigint::BigUint;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+/// A time-respecting walk, stored as its directed hops `(from, to, time)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Journey {
+    pub hops: Vec<(VertexId, VertexId, TimeStep)>,
+}
+
+impl Journey {
+    /// Timestamp of the first hop, or `None` for the empty journey
+    pub fn departure(&self) -> Option<TimeStep> {
+        self.hops.first().map(|&(_, _, t)| t)
+    }
+
+    /// Timestamp of the last hop, or `None` for the empty journey
+    pub fn arrival(&self) -> Option<TimeStep> {
+        self.hops.last().map(|&(_, _, t)| t)
+    }
+
+    /// Number of contacts used
+    pub fn hop_count(&self) -> usize {
+        self.hops.len()
+    }
+
+    /// Sequence of visited vertices, starting with the source
+    pub fn vertices(&self) -> Vec<VertexId> {
+        let mut vertices: Vec<VertexId> = self.hops.iter().take(1).map(|&(u, _, _)| u).collect();
+        vertices.extend(self.hops.iter().map(|&(_, v, _)| v));
+        vertices
+    }
+}
+
+impl TemporalGraph {
+    /// Count the distinct journeys from `source` to `target`.
+    ///
+    /// Journeys are time-respecting walks: sequences of contacts whose
+    /// timestamps increase (strictly or not) and may revisit vertices. Two
+    /// journeys are distinct when their contact sequences differ. When
+    /// `source == target` the empty journey is counted as well.
+    ///
+    /// Counts are computed by dynamic programming over contacts in time
+    /// order and hop count, with arbitrary-precision integers so they never
+    /// overflow. In strict mode the number of hops is bounded by the number
+    /// of distinct timestamps, and `max_hops` is clamped to it. In
+    /// non-strict mode without `max_hops`, any journey can bounce back and
+    /// forth on one of its contacts forever, so the count is infinite as
+    /// soon as one non-empty journey exists; `None` is returned in that
+    /// case, and also when `max_hops` is `usize::MAX`. The running time and
+    /// memory grow linearly with `max_hops` in non-strict mode.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    /// - `max_hops`: only count journeys with at most this many contacts.
+    pub fn count_journeys(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+        max_hops: Option<usize>,
+    ) -> Option<BigUint> {
+        if !self.has_vertex(source) || !self.has_vertex(target) {
+            return Some(BigUint::ZERO);
+        }
+
+        let trivial = if source == target {
+            BigUint::from(1u32)
+        } else {
+            BigUint::ZERO
+        };
+
+        let contacts = self.contacts();
+
+        let max_hops = match (max_hops, strict) {
+            (Some(k), false) => k,
+            (k, true) => {
+                let mut times: Vec<TimeStep> = contacts.iter().map(|&(_, _, t)| t).collect();
+                times.dedup();
+                k.map_or(times.len(), |k| k.min(times.len()))
+            }
+            (None, false) => {
+                let bounces = if source == target {
+                    self.get_all_neighbors(source).is_empty()
+                } else {
+                    !self.reachable_from(source, false).contains(&target)
+                };
+                return if bounces { Some(trivial) } else { None };
+            }
+        };
+
+        let levels = max_hops.checked_add(1)?;
+
+        // before[v][h]: journeys with h hops ending at v strictly before the
+        // current timestamp
+        let mut before: HashMap<VertexId, Vec<BigUint>> = HashMap::new();
+        let mut total = trivial;
+
+        let mut start = 0;
+        while start < contacts.len() {
+            let t = contacts[start].2;
+            let mut end = start;
+            while end < contacts.len() && contacts[end].2 == t {
+                end += 1;
+            }
+
+            // at_t[v][h]: journeys with h hops whose last contact is at time t
+            let mut at_t: HashMap<VertexId, Vec<BigUint>> = HashMap::new();
+            for h in 1..=max_hops {
+                for &(u, v, _) in &contacts[start..end] {
+                    for (a, b) in [(u, v), (v, u)] {
+                        let mut ending = BigUint::ZERO;
+                        if h == 1 && a == source {
+                            ending += 1u32;
+                        }
+                        if let Some(counts) = before.get(&a) {
+                            ending += &counts[h - 1];
+                        }
+                        if !strict && let Some(counts) = at_t.get(&a) {
+                            ending += &counts[h - 1];
+                        }
+                        if ending == BigUint::ZERO {
+                            continue;
+                        }
+                        if b == target {
+                            total += &ending;
+                        }
+                        at_t.entry(b).or_insert_with(|| vec![BigUint::ZERO; levels])[h] += ending;
+                    }
+                }
+            }
+
+            for (v, counts) in at_t {
+                let acc = before
+                    .entry(v)
+                    .or_insert_with(|| vec![BigUint::ZERO; levels]);
+                for (slot, count) in acc.iter_mut().zip(counts) {
+                    *slot += count;
+                }
+            }
+
+            start = end;
+        }
+
+        Some(total)
+    }
+
+    /// Lazily enumerate the journeys from `source` to `target`, in order of
+    /// arrival time.
+    ///
+    /// Journeys arriving at the same time come out with the fewest hops
+    /// first. When `source == target` the empty journey is yielded first.
+    /// Partial journeys are only extended towards vertices that can still
+    /// reach `target` afterwards (see `reaching_to`), so every expansion
+    /// eventually yields a journey. In non-strict mode the iterator is
+    /// usually infinite; use `take` to bound it.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn journeys(&self, source: VertexId, target: VertexId, strict: bool) -> JourneyIter {
+        let mut heap = BinaryHeap::new();
+        if self.has_vertex(source) && self.has_vertex(target) {
+            heap.push(Reverse((TimeStep::MIN, 0, 0, Vec::new())));
+        }
+
+        JourneyIter {
+            contact_lists: self.contact_lists(),
+            latest_departure: self.reaching_to(target, strict, None),
+            source,
+            target,
+            strict,
+            heap,
+            pushed: 1,
+        }
+    }
+}
+
+/// Partial journey in the search frontier: (last time, hops, insertion
+/// order, hops so far). Ordered so that `Reverse` makes the heap pop the
+/// earliest arrival first.
+type Frontier = Reverse<(TimeStep, usize, usize, Vec<(VertexId, VertexId, TimeStep)>)>;
+
+/// Iterator returned by `TemporalGraph::journeys`.
+pub struct JourneyIter {
+    contact_lists: HashMap<VertexId, Vec<(VertexId, TimeStep)>>,
+    latest_departure: HashMap<VertexId, TimeStep>,
+    source: VertexId,
+    target: VertexId,
+    strict: bool,
+    heap: BinaryHeap<Frontier>,
+    pushed: usize,
+}
+
+impl Iterator for JourneyIter {
+    type Item = Journey;
+
+    fn next(&mut self) -> Option<Journey> {
+        while let Some(Reverse((last, hop_count, _, hops))) = self.heap.pop() {
+            let current = hops.last().map_or(self.source, |&(_, v, _)| v);
+
+            for &(w, t) in self.contact_lists.get(&current).into_iter().flatten() {
+                let usable = hops.is_empty() || if self.strict { t > last } else { t >= last };
+                let useful = w == self.target
+                    || self
+                        .latest_departure
+                        .get(&w)
+                        .is_some_and(|&next| if self.strict { t < next } else { t <= next });
+                if !usable || !useful {
+                    continue;
+                }
+
+                let mut extended = hops.clone();
+                extended.push((current, w, t));
+                self.heap
+                    .push(Reverse((t, hop_count + 1, self.pushed, extended)));
+                self.pushed += 1;
+            }
+
+            if current == self.target {
+                return Some(Journey { hops });
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -[1,2]- 1 -[3]- 2
+    fn two_routes() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 2, 3);
+        g
+    }
+
+    #[test]
+    fn test_count_strict() {
+        let g = two_routes();
+        // 0-1@1 then 1-2@3, or 0-1@2 then 1-2@3
+        assert_eq!(
+            g.count_journeys(0, 2, true, None),
+            Some(BigUint::from(2u32))
+        );
+        // 0-1@1 or 0-1@2; bouncing back at 2 leaves no later contact
+        assert_eq!(
+            g.count_journeys(0, 1, true, None),
+            Some(BigUint::from(2u32))
+        );
+        // empty journey plus 0-1@1, 1-0@2
+        assert_eq!(
+            g.count_journeys(0, 0, true, None),
+            Some(BigUint::from(2u32))
+        );
+        assert_eq!(g.count_journeys(2, 0, true, None), Some(BigUint::ZERO));
+    }
+
+    #[test]
+    fn test_count_hop_bound() {
+        let g = two_routes();
+        assert_eq!(g.count_journeys(0, 2, true, Some(1)), Some(BigUint::ZERO));
+        assert_eq!(
+            g.count_journeys(0, 2, true, Some(2)),
+            Some(BigUint::from(2u32))
+        );
+        // Strict journeys have at most three hops here
+        assert_eq!(
+            g.count_journeys(0, 2, true, Some(usize::MAX)),
+            Some(BigUint::from(2u32))
+        );
+        assert_eq!(g.count_journeys(0, 2, false, Some(usize::MAX)), None);
+    }
+
+    #[test]
+    fn test_count_nonstrict() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 2, 5);
+
+        assert_eq!(g.count_journeys(0, 2, true, None), Some(BigUint::ZERO));
+        // Unbounded non-strict journeys can bounce on 0-1 forever
+        assert_eq!(g.count_journeys(0, 2, false, None), None);
+        // 0-1-2, then 0-1-0-1-2 and 0-1-2-1-2 once four hops are allowed
+        assert_eq!(
+            g.count_journeys(0, 2, false, Some(2)),
+            Some(BigUint::from(1u32))
+        );
+        assert_eq!(
+            g.count_journeys(0, 2, false, Some(4)),
+            Some(BigUint::from(3u32))
+        );
+    }
+
+    #[test]
+    fn test_count_does_not_overflow() {
+        // A chain of k edges with two labels each, ordered so that every
+        // combination is time-respecting: 2^k journeys end to end
+        let mut g = TemporalGraph::new();
+        let k = 80;
+        for i in 0..k {
+            g.add_edge(i, i + 1, 2 * i as TimeStep);
+            g.add_edge(i, i + 1, 2 * i as TimeStep + 1);
+        }
+        let count = g.count_journeys(0, k, true, None).unwrap();
+        assert_eq!(count, BigUint::from(2u32).pow(k as u32));
+    }
+
+    #[test]
+    fn test_journeys_in_arrival_order() {
+        let g = two_routes();
+        let all: Vec<Journey> = g.journeys(0, 1, true).collect();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].hops, vec![(0, 1, 1)]);
+        assert_eq!(all[1].hops, vec![(0, 1, 2)]);
+
+        let to_two: Vec<Journey> = g.journeys(0, 2, true).collect();
+        assert_eq!(to_two.len(), 2);
+        assert!(to_two.iter().all(|j| j.arrival() == Some(3)));
+        assert_eq!(to_two[0].vertices(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_journeys_agree_with_counts() {
+        let mut g = two_routes();
+        g.add_edge(0, 2, 4);
+        g.add_edge(1, 2, 5);
+
+        for (s, t) in [(0, 2), (2, 0), (1, 1)] {
+            let listed = g.journeys(s, t, true).count();
+            let counted = g.count_journeys(s, t, true, None).unwrap();
+            assert_eq!(BigUint::from(listed), counted);
+
+            let arrivals: Vec<Option<TimeStep>> =
+                g.journeys(s, t, true).map(|j| j.arrival()).collect();
+            assert!(arrivals.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn test_nonstrict_journeys_are_lazy() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 5);
+        let first: Vec<Journey> = g.journeys(0, 1, false).take(3).collect();
+        assert_eq!(first.len(), 3);
+        assert_eq!(first[0].hop_count(), 1);
+        assert_eq!(first[1].hop_count(), 3);
+    }
+}
@@ -1,15 +1,23 @@
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 pub type VertexId = usize;
 pub type TimeStep = i64;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GraphState {
     // Sorted representation for canonical comparison
     edge_labels: Vec<((VertexId, VertexId), Vec<TimeStep>)>,
 }
 
+impl GraphState {
+    /// Labels of every edge, sorted by edge
+    pub fn edge_labels(&self) -> &[((VertexId, VertexId), Vec<TimeStep>)] {
+        &self.edge_labels
+    }
+}
+
 // Undirected edge representation with temporal information
 #[derive(Debug, Clone)]
 pub struct TemporalEdge {
@@ -110,6 +118,35 @@ impl TemporalGraph {
         verts
     }
 
+    /// Get the total number of labels over all edges
+    pub fn contact_count(&self) -> usize {
+        self.edges.values().map(|edge| edge.timestamps.len()).sum()
+    }
+
+    /// Get every contact `(u, v, t)` with `u < v`, sorted by time then edge
+    pub fn contacts(&self) -> Vec<(VertexId, VertexId, TimeStep)> {
+        let mut contacts: Vec<(VertexId, VertexId, TimeStep)> = self
+            .edges
+            .iter()
+            .flat_map(|((u, v), edge)| edge.timestamps.iter().map(move |&t| (*u, *v, t)))
+            .collect();
+        contacts.sort_unstable_by_key(|&(u, v, t)| (t, u, v));
+        contacts
+    }
+
+    // Map every vertex to its (neighbor, time) contacts, sorted by time
+    pub(crate) fn contact_lists(&self) -> HashMap<VertexId, Vec<(VertexId, TimeStep)>> {
+        let mut lists: HashMap<VertexId, Vec<(VertexId, TimeStep)>> =
+            self.vertices.iter().map(|&v| (v, Vec::new())).collect();
+
+        for (u, v, t) in self.contacts() {
+            lists.entry(u).or_default().push((v, t));
+            lists.entry(v).or_default().push((u, t));
+        }
+
+        lists
+    }
+
     /// Remove a specific timestamp from an edge
     pub fn remove_edge_timestamp(&mut self, u: VertexId, v: VertexId, time: TimeStep) -> bool {
         let (u_norm, v_norm) = if u <= v { (u, v) } else { (v, u) };
@@ -141,6 +178,32 @@ impl TemporalGraph {
         }
     }
 
+    /// Build the subgraph induced by `vertices`.
+    ///
+    /// Only edges with both endpoints in `vertices` are kept, with all of
+    /// their timestamps. Ids in `vertices` that are not part of the graph
+    /// are ignored.
+    pub fn induced_subgraph(&self, vertices: &HashSet<VertexId>) -> Self {
+        let mut sub = TemporalGraph::new();
+
+        for &v in vertices {
+            if self.has_vertex(v) {
+                sub.add_vertex(v);
+                if let Some(label) = self.vertex_labels.get(&v) {
+                    sub.vertex_labels.insert(v, label.clone());
+                }
+            }
+        }
+
+        for ((u, v), edge) in &self.edges {
+            if vertices.contains(u) && vertices.contains(v) {
+                sub.edges.insert((*u, *v), edge.clone());
+            }
+        }
+
+        sub
+    }
+
     // Get neighbors of vertex at specific time
     pub fn neighbors_at_time(&self, vertex: VertexId, time: TimeStep) -> Vec<VertexId> {
         self.edges
@@ -409,7 +472,9 @@ mod tests {
 }
 pub mod minimization;
 pub use minimization::{
-    MinimizationConfig, MinimizationResult, MinimizationStats, TerminationReason,
+    CancellationToken, LabelMove, MinimalityVerdict, MinimizationConfig, MinimizationOperation,
+    MinimizationResult, MinimizationStateGraph, MinimizationStats, StateTransition,
+    TerminationReason,
 };
 pub mod enumeration;
 pub mod visualization;
@@ -418,4 +483,46 @@ pub use enumeration::{
     read_temporal_graphs_from_file,
 };
 pub mod connectivity;
-pub use connectivity::TemporalPathResult;
+pub use connectivity::{JourneyConstraints, ReachabilityGraph, TemporalPathResult};
+pub mod components;
+pub use components::ComponentKind;
+pub mod centrality;
+pub mod spanner;
+pub use centrality::{OptimalJourneys, TemporalDistance};
+pub mod dynamic;
+pub use dynamic::DynamicReachability;
+pub mod classes;
+pub use classes::{MAX_EXACT_PROPER_CONTACTS, TemporalClass};
+pub mod journeys;
+pub use journeys::{Journey, JourneyIter};
+pub mod separators;
+pub use separators::TemporalMengerReport;
+pub mod flow;
+pub use flow::{TemporalFlow, TemporalFlowConfig};
+pub mod time_expanded;
+pub use time_expanded::{TimeExpandedArc, TimeExpandedArcKind, TimeExpandedGraph};
+pub mod gossip;
+pub use gossip::{DisseminationReport, KnowledgeSnapshot, SpreadingModel};
+pub mod epidemic;
+pub use epidemic::{EpidemicBatch, EpidemicConfig, EpidemicModel, EpidemicRun};
+pub mod exploration;
+pub use exploration::Exploration;
+
+pub mod minimality_oracle;
+pub use minimality_oracle::{
+    MinimalityCrossCheck, MinimalityDisagreement, MinimalityOracleReport, cross_check_minimality,
+    cross_check_minimality_file,
+};
+pub mod selection;
+pub use selection::{SelectionPolicy, SelectionStrategy, WrappableEdge};
+pub mod branch_search;
+pub use branch_search::{BranchSearchConfig, BranchSearchReport, SearchOrder};
+pub mod observer;
+pub use observer::{CollectingObserver, MinimizationEvent, MinimizationObserver, StdoutObserver};
+pub mod checkpoint;
+pub use checkpoint::{
+    CHECKPOINT_VERSION, CheckpointSchedule, CheckpointedConfig, GraphSnapshot,
+    MinimizationCheckpoint, state_log_path,
+};
+pub mod audit;
+pub use audit::{AuditedStep, ReachabilityViolation};
@@ -0,0 +1,261 @@
+use crate::{
+    MinimalityVerdict, MinimizationConfig, TemporalGraph, TimeStep, VertexId,
+    read_temporal_graphs_from_file,
+};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::BTreeSet;
+
+/// Ground-truth label minimality of a temporal graph, by brute force.
+///
+/// A reduction is a subset of the contacts that keeps every reachable
+/// ordered pair reachable. Removing contacts never creates journeys, so the
+/// graph is label-minimal exactly when no single contact is removable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalityOracleReport {
+    pub strict: bool,
+    /// Number of reachable ordered pairs `(s, t)` with `s != t`.
+    pub reachable_pairs: usize,
+    /// Contacts whose removal alone keeps every reachable pair.
+    pub removable_labels: Vec<(VertexId, VertexId, TimeStep)>,
+    /// Contacts kept by an inclusion-minimal reduction.
+    pub inclusion_minimal_reduction: Vec<(VertexId, VertexId, TimeStep)>,
+    /// Contacts kept by a reduction of minimum cardinality.
+    pub minimum_reduction: Vec<(VertexId, VertexId, TimeStep)>,
+}
+
+impl MinimalityOracleReport {
+    /// Check whether no label can be removed
+    pub fn is_minimal(&self) -> bool {
+        self.removable_labels.is_empty()
+    }
+}
+
+/// A graph on which the minimization heuristic and the oracle disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalityDisagreement {
+    /// Position of the graph in the checked list.
+    pub index: usize,
+    pub verdict: MinimalityVerdict,
+    pub oracle: MinimalityOracleReport,
+}
+
+/// Outcome of `cross_check_minimality`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalityCrossCheck {
+    pub graphs_checked: usize,
+    /// Graphs with a conclusive verdict that contradicts the oracle.
+    pub disagreements: Vec<MinimalityDisagreement>,
+    /// Positions of the graphs the heuristic could not decide.
+    pub undetermined: Vec<usize>,
+}
+
+impl MinimalityCrossCheck {
+    /// Check whether every conclusive verdict matched the oracle
+    pub fn agrees(&self) -> bool {
+        self.disagreements.is_empty()
+    }
+}
+
+impl TemporalGraph {
+    /// Decide label minimality by brute force, as a ground truth for the
+    /// wrap-and-transfer heuristic of `LabelMinimizer`.
+    ///
+    /// Each contact is removed in turn, in parallel, and kept as removable
+    /// if the reachable ordered pairs stay the same. The inclusion-minimal
+    /// reduction drops removable contacts greedily in `contacts()` order.
+    /// Contacts that are not removable belong to every reduction, so the
+    /// minimum one is searched among subsets of the removable contacts, by
+    /// increasing size; this is exponential in their number, so only use
+    /// this on small graphs.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn minimality_oracle(&self, strict: bool) -> MinimalityOracleReport {
+        let expected = self.reachability_graph(strict).arcs;
+        let contacts = self.contacts();
+        let preserves = |kept: &[(VertexId, VertexId, TimeStep)]| {
+            self.with_contacts(kept).reachability_graph(strict).arcs == expected
+        };
+
+        let removable_labels: Vec<_> = contacts
+            .par_iter()
+            .copied()
+            .filter(|&removed| {
+                let kept: Vec<_> = contacts.iter().copied().filter(|&c| c != removed).collect();
+                preserves(&kept)
+            })
+            .collect();
+
+        let mut inclusion_minimal_reduction = contacts.clone();
+        for &removed in &removable_labels {
+            let kept: Vec<_> = inclusion_minimal_reduction
+                .iter()
+                .copied()
+                .filter(|&c| c != removed)
+                .collect();
+            if preserves(&kept) {
+                inclusion_minimal_reduction = kept;
+            }
+        }
+
+        let removable: BTreeSet<_> = removable_labels.iter().copied().collect();
+        let essential: Vec<_> = contacts
+            .iter()
+            .copied()
+            .filter(|c| !removable.contains(c))
+            .collect();
+        let minimum_reduction = (0..=removable_labels.len())
+            .find_map(|size| {
+                let subsets: Vec<Vec<_>> = removable_labels
+                    .iter()
+                    .copied()
+                    .combinations(size)
+                    .collect();
+                subsets.into_par_iter().find_map_first(|extra| {
+                    let mut kept = essential.clone();
+                    kept.extend(extra);
+                    kept.sort_unstable_by_key(|&(u, v, t)| (t, u, v));
+                    preserves(&kept).then_some(kept)
+                })
+            })
+            .unwrap_or(contacts);
+
+        MinimalityOracleReport {
+            strict,
+            reachable_pairs: expected.len(),
+            removable_labels,
+            inclusion_minimal_reduction,
+            minimum_reduction,
+        }
+    }
+}
+
+/// Run `LabelMinimizer` and `minimality_oracle` on every graph and list the
+/// graphs where a conclusive verdict contradicts the oracle.
+///
+/// # Parameters
+/// - `config`: minimizer configuration; its `strict` flag also drives the
+///   oracle.
+pub fn cross_check_minimality(
+    graphs: &[TemporalGraph],
+    config: &MinimizationConfig,
+) -> MinimalityCrossCheck {
+    let outcomes: Vec<(MinimalityVerdict, MinimalityOracleReport)> = graphs
+        .par_iter()
+        .map(|graph| {
+            let verdict = graph
+                .clone_graph()
+                .is_label_minimal_with_config(config.clone())
+                .verdict;
+            (verdict, graph.minimality_oracle(config.strict))
+        })
+        .collect();
+
+    let mut check = MinimalityCrossCheck {
+        graphs_checked: graphs.len(),
+        disagreements: Vec::new(),
+        undetermined: Vec::new(),
+    };
+    for (index, (verdict, oracle)) in outcomes.into_iter().enumerate() {
+        match verdict.as_bool() {
+            None => check.undetermined.push(index),
+            Some(minimal) if minimal != oracle.is_minimal() => {
+                check.disagreements.push(MinimalityDisagreement {
+                    index,
+                    verdict,
+                    oracle,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+
+    check
+}
+
+/// Same as `cross_check_minimality` on the graphs of a file, in the format
+/// of `read_temporal_graphs_from_file`
+pub fn cross_check_minimality_file(
+    filename: &str,
+    config: &MinimizationConfig,
+) -> Result<MinimalityCrossCheck, String> {
+    let graphs = read_temporal_graphs_from_file(filename)?;
+    Ok(cross_check_minimality(&graphs, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::minimization::tests::redundant_sample;
+
+    #[test]
+    fn test_removable_labels() {
+        let report = redundant_sample().minimality_oracle(true);
+
+        assert!(!report.is_minimal());
+        assert_eq!(report.reachable_pairs, 6);
+        // Only 1-2 at 2 is essential, but no two labels can go together
+        assert_eq!(
+            report.removable_labels,
+            vec![(0, 1, 1), (0, 1, 3), (0, 2, 5)]
+        );
+        assert_eq!(
+            report.inclusion_minimal_reduction,
+            vec![(1, 2, 2), (0, 1, 3), (0, 2, 5)]
+        );
+        assert_eq!(report.minimum_reduction.len(), 3);
+    }
+
+    #[test]
+    fn test_greedy_reduction_is_not_minimum() {
+        // Star around 0: dropping 0-2 at time 1 first forces keeping both
+        // labels of 0-1, while 0-2 at 1, 0-1 at 2, 0-3 at 4 is enough
+        let mut g = TemporalGraph::new();
+        for (u, v, t) in [(0, 2, 1), (0, 1, 2), (0, 2, 2), (0, 1, 4), (0, 3, 4)] {
+            g.add_edge(u, v, t);
+        }
+        let report = g.minimality_oracle(true);
+
+        assert_eq!(report.inclusion_minimal_reduction.len(), 4);
+        assert_eq!(
+            report.minimum_reduction,
+            vec![(0, 2, 1), (0, 1, 2), (0, 3, 4)]
+        );
+        let reduced = g.with_contacts(&report.minimum_reduction);
+        assert_eq!(
+            reduced.reachability_graph(true).arcs,
+            g.reachability_graph(true).arcs
+        );
+    }
+
+    #[test]
+    fn test_minimal_graph() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        let report = g.minimality_oracle(true);
+
+        assert!(report.is_minimal());
+        assert_eq!(report.minimum_reduction, g.contacts());
+    }
+
+    #[test]
+    fn test_cross_check_agrees() {
+        // The heuristic gets stuck on the minimal path above without a
+        // redundant contact, which leaves it undetermined
+        let mut minimal = TemporalGraph::new();
+        minimal.add_edge(0, 1, 1);
+        minimal.add_edge(0, 1, 3);
+        minimal.add_edge(1, 2, 2);
+
+        let graphs = vec![redundant_sample(), minimal];
+        let check = cross_check_minimality(&graphs, &MinimizationConfig::new());
+
+        assert_eq!(check.graphs_checked, 2);
+        assert_eq!(check.undetermined, vec![1]);
+        assert!(check.disagreements.is_empty());
+        assert!(check.agrees());
+    }
+}
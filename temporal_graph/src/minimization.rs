@@ -1,5 +1,17 @@
-use crate::{GraphState, TemporalGraph, TimeStep, VertexId};
-use std::collections::HashSet;
+use crate::audit::ReachabilityAudit;
+use crate::checkpoint::{CheckpointHeader, write_state_log};
+use crate::{
+    AuditedStep, CHECKPOINT_VERSION, CheckpointSchedule, CheckpointedConfig, GraphSnapshot,
+    GraphState, MinimizationCheckpoint, MinimizationObserver, ReachabilityViolation,
+    SelectionPolicy, SelectionStrategy, StdoutObserver, TemporalGraph, TimeStep, VertexId,
+    WrappableEdge,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 /// Configuration for the label minimization algorithm
 #[derive(Debug, Clone)]
@@ -7,19 +19,61 @@ pub struct MinimizationConfig {
     /// Maximum number of iterations before forced termination
     pub max_iterations: Option<usize>,
 
+    /// Maximum wall-clock time of a run
+    pub time_budget: Option<Duration>,
+
+    /// Maximum number of states kept for cycle detection
+    pub max_stored_states: Option<usize>,
+
+    /// Token to stop a run from another thread
+    pub cancellation: Option<CancellationToken>,
+
     /// Whether to track detailed statistics during execution
     pub track_statistics: bool,
 
-    /// Whether to print debug information
+    /// Whether to print debug information, through `StdoutObserver`
     pub verbose: bool,
+
+    /// Observers notified at every step of a run
+    pub observers: Vec<Arc<dyn MinimizationObserver>>,
+
+    /// Whether journeys use strictly increasing timestamps when checking
+    /// which labels are redundant
+    pub strict: bool,
+
+    /// How to choose among wrappable edges and tied incident labels
+    pub selection: Arc<dyn SelectionStrategy>,
+
+    /// Whether to return the visited states and transitions
+    pub record_state_graph: bool,
+
+    /// Where and how often to write checkpoints
+    pub checkpoint: Option<CheckpointSchedule>,
+
+    /// Whether to check that every step keeps the reachable pairs
+    pub audit: bool,
+
+    /// Whether to look for the redundant contacts backing a useless-label
+    /// claim, which takes one reachability computation per contact
+    pub find_redundant_contacts: bool,
 }
 
 impl Default for MinimizationConfig {
     fn default() -> Self {
         Self {
             max_iterations: Some(10_000),
+            time_budget: None,
+            max_stored_states: None,
+            cancellation: None,
             track_statistics: false,
             verbose: false,
+            observers: Vec::new(),
+            strict: true,
+            selection: Arc::new(SelectionPolicy::Lexicographic),
+            record_state_graph: false,
+            checkpoint: None,
+            audit: false,
+            find_redundant_contacts: true,
         }
     }
 }
@@ -42,6 +96,24 @@ impl MinimizationConfig {
         self
     }
 
+    /// Builder method: stop runs taking longer than `budget`
+    pub fn with_time_budget(mut self, budget: Duration) -> Self {
+        self.time_budget = Some(budget);
+        self
+    }
+
+    /// Builder method: stop runs before storing more than `max` states
+    pub fn with_max_stored_states(mut self, max: usize) -> Self {
+        self.max_stored_states = Some(max);
+        self
+    }
+
+    /// Builder method: stop runs once `token` is cancelled
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
     /// Builder method: enable statistics tracking
     pub fn with_statistics(mut self) -> Self {
         self.track_statistics = true;
@@ -53,10 +125,63 @@ impl MinimizationConfig {
         self.verbose = true;
         self
     }
+
+    /// Builder method: add an observer, keeping a clone of the `Arc` to
+    /// inspect it after the run
+    pub fn with_observer(mut self, observer: Arc<dyn MinimizationObserver>) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// Builder method: check redundancy with non-decreasing journeys
+    pub fn non_strict(mut self) -> Self {
+        self.strict = false;
+        self
+    }
+
+    /// Builder method: record the state graph of the run
+    pub fn with_state_graph(mut self) -> Self {
+        self.record_state_graph = true;
+        self
+    }
+
+    /// Builder method: set the edge and incident label selection strategy
+    pub fn with_selection(mut self, selection: impl SelectionStrategy + 'static) -> Self {
+        self.selection = Arc::new(selection);
+        self
+    }
+
+    /// Builder method: compare all-pairs reachability before and after
+    /// every transfer and `tmin` move, stopping at the first change
+    pub fn with_audit(mut self) -> Self {
+        self.audit = true;
+        self
+    }
+
+    /// Builder method: skip the search for redundant contacts when the run
+    /// gets stuck, which then ends with an undetermined verdict
+    pub fn without_redundant_contacts(mut self) -> Self {
+        self.find_redundant_contacts = false;
+        self
+    }
+
+    /// Builder method: write a checkpoint to `filename` every `every`
+    /// iterations, see `MinimizationCheckpoint`
+    ///
+    /// # Panics
+    /// Panics if `every` is 0.
+    pub fn with_checkpoints(mut self, every: usize, filename: &str) -> Self {
+        assert!(every > 0, "checkpoint interval must be positive");
+        self.checkpoint = Some(CheckpointSchedule {
+            every,
+            filename: filename.to_string(),
+        });
+        self
+    }
 }
 
 /// Statistics collected during algorithm execution
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MinimizationStats {
     /// Number of iterations performed
     pub iterations: usize,
@@ -70,8 +195,19 @@ pub struct MinimizationStats {
     /// Number of useless labels detected
     pub useless_labels_found: usize,
 
-    /// Number of unique states visited
+    /// Number of unique states visited. States are never evicted, so this
+    /// is also the peak number of stored states that `max_stored_states`
+    /// bounds.
     pub states_visited: usize,
+
+    /// Number of operations in the cycle that ended the run
+    pub cycle_length: Option<usize>,
+
+    /// Index of the state where that cycle starts
+    pub cycle_entry: Option<usize>,
+
+    /// Wall-clock duration of the run
+    pub elapsed: Duration,
 }
 
 impl MinimizationStats {
@@ -83,7 +219,11 @@ impl MinimizationStats {
 /// Result of the minimization algorithm
 #[derive(Debug, Clone)]
 pub struct MinimizationResult {
-    /// Whether the graph is minimal with respect to the algorithm
+    /// Conclusion of the algorithm, with its evidence
+    pub verdict: MinimalityVerdict,
+
+    /// Whether the graph was proven minimal, i.e. `verdict.is_minimal()`
+    #[deprecated(note = "use `verdict`, which tells undetermined runs apart")]
     pub is_minimal: bool,
 
     /// Statistics about the execution (if tracking was enabled)
@@ -91,6 +231,72 @@ pub struct MinimizationResult {
 
     /// Reason for termination
     pub termination_reason: TerminationReason,
+
+    /// Graph state when the algorithm stopped
+    pub final_state: GraphState,
+
+    /// Operations applied to the input graph, in order
+    pub operations: Vec<MinimizationOperation>,
+
+    /// Visited states and transitions, if recording was enabled
+    pub state_graph: Option<MinimizationStateGraph>,
+
+    /// Step that changed the reachable pairs, for audited runs ending with
+    /// `TerminationReason::ReachabilityViolated`
+    pub audit_violation: Option<ReachabilityViolation>,
+}
+
+impl MinimizationResult {
+    /// Redundant contacts of a `NotMinimal` verdict, empty otherwise
+    pub fn redundant_contacts(&self) -> &[(VertexId, VertexId, TimeStep)] {
+        match &self.verdict {
+            MinimalityVerdict::NotMinimal { redundant_contacts } => redundant_contacts,
+            _ => &[],
+        }
+    }
+
+    /// Replay the recorded operations on a copy of `original`.
+    ///
+    /// Returns `None` if an operation is not legal at its step or if the
+    /// outcome differs from `final_state`.
+    pub fn replay(&self, original: &TemporalGraph) -> Option<TemporalGraph> {
+        let mut graph = original.clone_graph();
+        for operation in &self.operations {
+            if !operation.apply(&mut graph) {
+                return None;
+            }
+        }
+
+        (graph.to_state() == self.final_state).then_some(graph)
+    }
+
+    /// Check the non-minimality certificate against `original`.
+    ///
+    /// The certificate holds if the operations replay to `final_state` and
+    /// removing any one of the redundant contacts from the final graph
+    /// gives a graph with fewer labels than `original` and the same
+    /// reachable pairs.
+    ///
+    /// # Parameters
+    /// - `strict`: journey semantics of the reachability comparison.
+    pub fn verify_certificate(&self, original: &TemporalGraph, strict: bool) -> bool {
+        let Some(graph) = self.replay(original) else {
+            return false;
+        };
+        let expected = original.reachability_graph(strict).arcs;
+        let redundant_contacts = self.redundant_contacts();
+
+        !redundant_contacts.is_empty()
+            && redundant_contacts.iter().all(|&(u, v, t)| {
+                if !graph.has_edge_at_time(u, v, t) {
+                    return false;
+                }
+                let mut reduced = graph.clone_graph();
+                reduced.remove_edge_timestamp(u, v, t);
+                reduced.contact_count() < original.contact_count()
+                    && reduced.reachability_graph(strict).arcs == expected
+            })
+    }
 }
 
 /// Reason why the algorithm terminated
@@ -99,11 +305,250 @@ pub enum TerminationReason {
     /// A cycle was detected - graph is minimal
     CycleDetected,
 
-    /// A useless label was found - graph is not minimal
+    /// No operation applies anymore - graph is not minimal if a redundant
+    /// contact backs the claim
     UselessLabelFound,
 
     /// Maximum iterations reached
     MaxIterationsReached,
+
+    /// The time budget ran out
+    TimeBudgetExceeded,
+
+    /// Storing one more state would exceed `max_stored_states`
+    StateLimitReached,
+
+    /// The cancellation token was triggered
+    Cancelled,
+
+    /// An audited step changed the reachable pairs
+    ReachabilityViolated,
+}
+
+/// Shared flag asking a running `LabelMinimizer` to stop.
+///
+/// Clones share the same flag, so a clone can be cancelled from another
+/// thread. The run checks it at the start of every iteration.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the runs using this token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Three-state outcome of the minimization algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimalityVerdict {
+    /// The algorithm came back to an earlier state: the graph is minimal.
+    ///
+    /// The operations from index `cycle_start` on, `cycle_length` of them,
+    /// lead from the repeated state back to itself.
+    Minimal {
+        cycle_start: usize,
+        cycle_length: usize,
+    },
+
+    /// The algorithm got stuck and the graph is not minimal.
+    ///
+    /// `redundant_contacts` are the contacts of the final graph whose
+    /// removal keeps every pair reachable in the input graph reachable,
+    /// see `MinimizationResult::verify_certificate`. It is never empty: a
+    /// stuck run without such a contact is `Undetermined`.
+    NotMinimal {
+        redundant_contacts: Vec<(VertexId, VertexId, TimeStep)>,
+    },
+
+    /// The run stopped before reaching a conclusion, or got stuck without
+    /// a redundant contact to back a `NotMinimal` claim.
+    Undetermined { reason: TerminationReason },
+}
+
+impl MinimalityVerdict {
+    pub fn is_minimal(&self) -> bool {
+        matches!(self, MinimalityVerdict::Minimal { .. })
+    }
+
+    pub fn is_not_minimal(&self) -> bool {
+        matches!(self, MinimalityVerdict::NotMinimal { .. })
+    }
+
+    pub fn is_undetermined(&self) -> bool {
+        matches!(self, MinimalityVerdict::Undetermined { .. })
+    }
+
+    /// `Some(minimal)` for a conclusive verdict, `None` otherwise
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            MinimalityVerdict::Minimal { .. } => Some(true),
+            MinimalityVerdict::NotMinimal { .. } => Some(false),
+            MinimalityVerdict::Undetermined { .. } => None,
+        }
+    }
+}
+
+/// A label moved from one edge to another, keeping its timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct LabelMove {
+    pub from: (VertexId, VertexId),
+    pub to: (VertexId, VertexId),
+    pub time: TimeStep,
+}
+
+/// One iteration of the minimization algorithm
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinimizationOperation {
+    /// The wrappable edge `{u, v}`
+    pub wrappable_edge: (VertexId, VertexId),
+
+    /// Minimum incident label `(w, x, t)`: `x` is the common vertex and `w`
+    /// its neighbor
+    pub incident: (VertexId, VertexId, TimeStep),
+
+    /// Labels moved by the transfer through `{x, other endpoint}`
+    pub transferred: Vec<LabelMove>,
+
+    /// The `tmin` label of the wrappable edge, moved to `{w, other endpoint}`
+    pub wrapped: LabelMove,
+}
+
+impl MinimizationOperation {
+    /// Check that the operation is legal on `graph`, then apply it.
+    ///
+    /// Legal means that the edge is wrappable with `tmin` equal to the
+    /// wrapped label, that the incident label lies in `(tmin, tmax)`, and
+    /// that the transfer moves exactly the recorded labels. Returns `false`
+    /// otherwise, in which case `graph` may be partially modified.
+    pub fn apply(&self, graph: &mut TemporalGraph) -> bool {
+        let (u, v) = self.wrappable_edge;
+        let (w, x, t) = self.incident;
+
+        let Some((tmin, tmax)) = graph.get_edge_time_range(u, v) else {
+            return false;
+        };
+        let other_endpoint = if x == u { v } else { u };
+        let legal = tmin < tmax
+            && (x == u || x == v)
+            && t > tmin
+            && t < tmax
+            && graph.has_edge_at_time(w, x, t)
+            && self.wrapped.time == tmin
+            && same_edge(self.wrapped.from, (u, v))
+            && same_edge(self.wrapped.to, (w, other_endpoint));
+        if !legal {
+            return false;
+        }
+
+        let moved: BTreeSet<LabelMove> = graph
+            .transfer_labels_with_moves(x, other_endpoint)
+            .into_iter()
+            .collect();
+        if moved != self.transferred.iter().copied().collect() {
+            return false;
+        }
+
+        graph.add_edge(w, other_endpoint, tmin);
+        graph.remove_edge_timestamp(u, v, tmin)
+    }
+}
+
+/// Step of a minimization run between two recorded states.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateTransition {
+    pub from: usize,
+    pub to: usize,
+    pub operation: MinimizationOperation,
+    /// Whether the transition belongs to the cycle that ended the run.
+    pub in_cycle: bool,
+}
+
+/// States visited by a minimization run and the operations between them.
+///
+/// The run is a single path: transition `i` leaves state `i`, and only the
+/// last one can lead back to an earlier state, closing the cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MinimizationStateGraph {
+    /// Visited states in visit order; state 0 is the input graph.
+    pub states: Vec<GraphState>,
+    pub transitions: Vec<StateTransition>,
+    /// State where the cycle starts, for runs ending with
+    /// `TerminationReason::CycleDetected`.
+    pub cycle_entry: Option<usize>,
+}
+
+impl MinimizationStateGraph {
+    /// Number of transitions in the cycle, if any
+    pub fn cycle_length(&self) -> Option<usize> {
+        self.cycle_entry
+            .map(|_| self.transitions.iter().filter(|step| step.in_cycle).count())
+    }
+
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("state graphs serialize to JSON")
+    }
+
+    /// Parse a state graph written by `to_json`
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid state graph: {}", e))
+    }
+
+    /// Write the JSON form to `filename`
+    pub fn save_json(&self, filename: &str) -> std::io::Result<()> {
+        fs::write(filename, self.to_json())
+    }
+}
+
+/// Perform one wrap of `edge` using the incident label `incident`: transfer
+/// the labels of the neighbors of the common vertex through the edge, then
+/// move its `tmin` label next to the incident one.
+///
+/// `after_transfer` sees the graph and the moved labels between the two
+/// steps; returning `false` stops the wrap there. Returns `None` if the
+/// wrap was stopped or the `tmin` label could not be moved.
+pub(crate) fn wrap_edge(
+    graph: &mut TemporalGraph,
+    edge: (VertexId, VertexId),
+    incident: (VertexId, VertexId, TimeStep),
+    after_transfer: impl FnOnce(&TemporalGraph, &[LabelMove]) -> bool,
+) -> Option<MinimizationOperation> {
+    let (u, v) = edge;
+    let (w, x, _) = incident;
+    let other_endpoint = if x == u { v } else { u };
+
+    let transferred = graph.transfer_labels_with_moves(x, other_endpoint);
+    if !after_transfer(graph, &transferred) {
+        return None;
+    }
+    let (tmin, _) = graph.get_edge_time_range(u, v)?;
+    graph.add_edge(w, other_endpoint, tmin);
+
+    graph
+        .remove_edge_timestamp(u, v, tmin)
+        .then_some(MinimizationOperation {
+            wrappable_edge: edge,
+            incident,
+            transferred,
+            wrapped: LabelMove {
+                from: edge,
+                to: (w, other_endpoint),
+                time: tmin,
+            },
+        })
+}
+
+fn same_edge(a: (VertexId, VertexId), b: (VertexId, VertexId)) -> bool {
+    a == b || a == (b.1, b.0)
 }
 
 /// Main algorithm executor
@@ -111,7 +556,14 @@ pub struct LabelMinimizer<'a> {
     graph: &'a mut TemporalGraph,
     config: MinimizationConfig,
     stats: MinimizationStats,
-    seen_states: HashSet<GraphState>,
+    seen_states: HashMap<GraphState, usize>,
+    operations: Vec<MinimizationOperation>,
+    started: Instant,
+    elapsed_before: Duration,
+    initial: Option<TemporalGraph>,
+    audit: Option<ReachabilityAudit>,
+    violation: Option<ReachabilityViolation>,
+    logged_states: usize,
 }
 
 impl<'a> LabelMinimizer<'a> {
@@ -121,7 +573,14 @@ impl<'a> LabelMinimizer<'a> {
             graph,
             config: MinimizationConfig::default(),
             stats: MinimizationStats::new(),
-            seen_states: HashSet::new(),
+            seen_states: HashMap::new(),
+            operations: Vec::new(),
+            started: Instant::now(),
+            elapsed_before: Duration::ZERO,
+            initial: None,
+            audit: None,
+            violation: None,
+            logged_states: 0,
         }
     }
 
@@ -131,169 +590,406 @@ impl<'a> LabelMinimizer<'a> {
             graph,
             config,
             stats: MinimizationStats::new(),
-            seen_states: HashSet::new(),
+            seen_states: HashMap::new(),
+            operations: Vec::new(),
+            started: Instant::now(),
+            elapsed_before: Duration::ZERO,
+            initial: None,
+            audit: None,
+            violation: None,
+            logged_states: 0,
+        }
+    }
+
+    /// Continue the run saved in `checkpoint`, replacing `graph` with the
+    /// checkpointed graph.
+    ///
+    /// The configuration is not read from the checkpoint, so that observers,
+    /// cancellation tokens and custom strategies can be supplied again;
+    /// `MinimizationCheckpoint::config` gives back the saved one. The time
+    /// budget counts the time spent before the checkpoint.
+    pub fn from_checkpoint(
+        graph: &'a mut TemporalGraph,
+        checkpoint: &MinimizationCheckpoint,
+        config: MinimizationConfig,
+    ) -> Self {
+        *graph = checkpoint.graph.to_graph();
+        let seen_states = checkpoint
+            .seen_states
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, state)| (state, index))
+            .collect();
+
+        Self {
+            graph,
+            config,
+            stats: checkpoint.stats.clone(),
+            seen_states,
+            operations: checkpoint.operations.clone(),
+            started: Instant::now(),
+            elapsed_before: checkpoint.stats.elapsed,
+            initial: Some(checkpoint.initial.to_graph()),
+            audit: None,
+            violation: None,
+            logged_states: 0,
         }
     }
 
     /// Run the label minimization algorithm
     pub fn run(&mut self) -> MinimizationResult {
-        // Initialize with the starting state
-        let initial_state = self.graph.to_state();
-        self.seen_states.insert(initial_state);
-        self.stats.states_visited = 1;
-
-        if self.config.verbose {
-            println!("Starting label minimization algorithm");
-            println!();
-            self.graph.print_state();
+        self.started = Instant::now();
+
+        // Keep the input to check redundant labels against at the end; a
+        // resumed run already has it
+        let initial = match self.initial.take() {
+            Some(initial) => initial,
+            None => {
+                let initial = self.graph.clone_graph();
+
+                // Initialize with the starting state
+                let initial_state = self.graph.to_state();
+                self.seen_states.insert(initial_state, 0);
+                self.stats.states_visited = 1;
+
+                self.notify(|observer| observer.run_started(self.graph));
+                initial
+            }
+        };
+        if self.config.audit {
+            self.audit = Some(ReachabilityAudit::new(self.graph, self.config.strict));
         }
 
         loop {
             self.stats.iterations += 1;
-            if self.config.verbose {
-                println!("\n=== Iteration {} ===", self.stats.iterations);
-            }
+            let iteration = self.stats.iterations;
+            self.notify(|observer| observer.iteration_started(iteration));
             if self.should_terminate_iterations() {
-                if self.config.verbose {
-                    println!("Max iterations reached");
-                }
-                return MinimizationResult {
-                    is_minimal: false,
-                    stats: if self.config.track_statistics {
-                        Some(self.stats.clone())
-                    } else {
-                        None
-                    },
-                    termination_reason: TerminationReason::MaxIterationsReached,
-                };
+                return self.finish(&initial, TerminationReason::MaxIterationsReached);
+            }
+            if let Some(reason) = self.interruption() {
+                return self.finish(&initial, reason);
             }
             let (u, v) = match self.find_wrappable_edge() {
                 Some(edge) => edge,
                 None => {
-                    if self.config.verbose {
-                        println!("No wrappable edge found - checking if useless label detected");
-                    }
+                    self.notify(|observer| observer.no_wrappable_edge());
                     // No wrappable edge means we can't continue
                     // This is a stopping condition, need to determine if minimal
                     break;
                 }
             };
-            if self.config.verbose {
-                println!("Found wrappable edge: ({}, {})", u, v);
-            }
+            self.notify(|observer| observer.wrappable_edge_found(u, v));
             let (w, x, t) = match self.find_min_incident_in_range(u, v) {
                 Some(result) => result,
                 None => {
-                    if self.config.verbose {
-                        println!("Warning: wrappable edge has no incident edges in range");
-                    }
+                    self.notify(|observer| {
+                        observer.warning("wrappable edge has no incident edges in range")
+                    });
                     break;
                 }
             };
 
-            if self.config.verbose {
-                println!(
-                    "Found incident: w={} (neighbor), x={} (common vertex), t={}",
-                    w, x, t
-                );
-            }
             // Determine the other endpoint of edge e
             let other_endpoint = if x == u { v } else { u };
 
-            if self.config.verbose {
-                println!("Other endpoint of e: {}", other_endpoint);
+            // Step 5: Transfer labels of neighbors of x through edge
+            // (x, other_endpoint), then move tmin
+            let config = &self.config;
+            let audit = self.audit.as_ref();
+            let stats = &mut self.stats;
+            let mut violation = None;
+            let operation = wrap_edge(self.graph, (u, v), (w, x, t), |graph, moves| {
+                if config.track_statistics {
+                    stats.transfers_attempted += 1;
+                    if !moves.is_empty() {
+                        stats.transfers_successful += 1;
+                    }
+                }
+                notify(config, |observer| {
+                    observer.labels_transferred((w, x, t), (x, other_endpoint), moves)
+                });
+                violation = audit_step(audit, graph, iteration, (u, v), (w, x, t), || {
+                    AuditedStep::Transfer {
+                        through: (x, other_endpoint),
+                        moves: moves.to_vec(),
+                    }
+                });
+                violation.is_none()
+            });
+            if violation.is_some() {
+                self.violation = violation;
+                return self.finish(&initial, TerminationReason::ReachabilityViolated);
             }
+            let Some(operation) = operation else {
+                self.notify(|observer| observer.warning("failed to remove tmin"));
+                break;
+            };
 
-            // Step 5: Transfer labels of neighbors of x through edge (x, other_endpoint)
-            if self.config.verbose {
-                println!(
-                    "Transferring labels through edge ({}, {})",
-                    x, other_endpoint
-                );
+            let wrapped = operation.wrapped;
+            self.operations.push(operation);
+            self.notify(|observer| observer.label_moved(wrapped, self.graph));
+            if let Some(violation) = audit_step(
+                self.audit.as_ref(),
+                self.graph,
+                iteration,
+                (u, v),
+                (w, x, t),
+                || AuditedStep::TminMove(wrapped),
+            ) {
+                self.violation = Some(violation);
+                return self.finish(&initial, TerminationReason::ReachabilityViolated);
             }
-            let transferred = self.transfer_labels(x, other_endpoint);
 
-            let (tmin, _tmax) = self.graph.get_edge_time_range(u, v).unwrap();
-            if self.config.verbose {
-                println!("Transferred {} labels", transferred);
-            }
-            if self.config.verbose {
-                println!("Removing tmin={} from edge ({}, {})", tmin, u, v);
+            // Check if we've seen this state before (cycle detection)
+            if self.has_seen_current_state() {
+                return self.finish(&initial, TerminationReason::CycleDetected);
             }
 
-            if self.config.verbose {
-                println!("Adding tmin={} to edge ({}, {})", tmin, other_endpoint, w);
+            if self
+                .config
+                .max_stored_states
+                .is_some_and(|max| self.seen_states.len() >= max)
+            {
+                return self.finish(&initial, TerminationReason::StateLimitReached);
             }
-            self.graph.add_edge(w, other_endpoint, tmin);
 
-            let removed = self.graph.remove_edge_timestamp(u, v, tmin);
-            if !removed {
-                if self.config.verbose {
-                    println!("Warning: failed to remove tmin");
+            // Record the new state
+            let state = self.record_current_state();
+            let states_visited = self.stats.states_visited;
+            self.notify(|observer| observer.state_recorded(&state, states_visited));
+            self.checkpoint_if_due(&initial, iteration);
+        }
+
+        // If we exit the loop without finding a cycle or useless label
+        // We consider it minimal (no more transformations possible)
+        self.finish(&initial, TerminationReason::UselessLabelFound)
+    }
+
+    /// Build the result, looking for the redundant contacts behind a
+    /// useless-label claim
+    fn finish(
+        &mut self,
+        initial: &TemporalGraph,
+        mut reason: TerminationReason,
+    ) -> MinimizationResult {
+        // The search can be stopped by the budget or the cancellation token
+        let redundant_contacts = match reason {
+            TerminationReason::UselessLabelFound if self.config.find_redundant_contacts => {
+                match self.redundant_contacts(initial) {
+                    Ok(redundant_contacts) => Some(redundant_contacts),
+                    Err(interruption) => {
+                        reason = interruption;
+                        None
+                    }
                 }
-                break;
             }
-            if self.config.verbose {
-                println!();
-                self.graph.print_state();
+            _ => None,
+        };
+        self.stats.elapsed = self.elapsed();
+        self.notify(|observer| observer.terminated(&reason, self.graph));
+
+        let verdict = match reason {
+            TerminationReason::CycleDetected => {
+                let cycle_start = self.seen_states[&self.graph.to_state()];
+                self.stats.cycle_entry = Some(cycle_start);
+                self.stats.cycle_length = Some(self.operations.len() - cycle_start);
+                MinimalityVerdict::Minimal {
+                    cycle_start,
+                    cycle_length: self.operations.len() - cycle_start,
+                }
             }
-            // Check if we've seen this state before (cycle detection)
-            if self.has_seen_current_state() {
-                if self.config.verbose {
-                    println!("Cycle detected! Graph is minimal");
+            TerminationReason::UselessLabelFound => {
+                let redundant_contacts = redundant_contacts.unwrap_or_default();
+                if self.config.track_statistics {
+                    self.stats.useless_labels_found = redundant_contacts.len();
+                }
+                if redundant_contacts.is_empty() {
+                    MinimalityVerdict::Undetermined {
+                        reason: reason.clone(),
+                    }
+                } else {
+                    MinimalityVerdict::NotMinimal { redundant_contacts }
                 }
-                return MinimizationResult {
-                    is_minimal: true,
-                    stats: if self.config.track_statistics {
-                        Some(self.stats.clone())
-                    } else {
-                        None
-                    },
-                    termination_reason: TerminationReason::CycleDetected,
-                };
             }
+            _ => MinimalityVerdict::Undetermined {
+                reason: reason.clone(),
+            },
+        };
 
-            // Record the new state
-            self.record_current_state();
-
-            if self.config.verbose {
-                println!(
-                    "New state recorded (total states: {})",
-                    self.stats.states_visited
-                );
-            }
-        }
+        let state_graph = self.config.record_state_graph.then(|| self.state_graph());
 
-        // If we exit the loop without finding a cycle or useless label
-        // We consider it minimal (no more transformations possible)
-        if self.config.verbose {
-            println!("Algorithm terminated");
-            println!("Graph is not minimal (no cycling)");
-            println!();
-            self.graph.print_state();
-        }
+        #[allow(deprecated)]
         MinimizationResult {
-            is_minimal: false,
+            is_minimal: verdict.is_minimal(),
+            verdict,
             stats: if self.config.track_statistics {
                 Some(self.stats.clone())
             } else {
                 None
             },
-            termination_reason: TerminationReason::UselessLabelFound,
+            termination_reason: reason,
+            final_state: self.graph.to_state(),
+            operations: self.operations.clone(),
+            state_graph,
+            audit_violation: self.violation.take(),
+        }
+    }
+
+    /// Seen states in visit order
+    fn visited_states(&self) -> Vec<GraphState> {
+        let mut states: Vec<GraphState> = vec![GraphState::default(); self.seen_states.len()];
+        for (state, &index) in &self.seen_states {
+            states[index] = state.clone();
+        }
+        states
+    }
+
+    /// Order the seen states by visit and link them with the operations
+    fn state_graph(&self) -> MinimizationStateGraph {
+        let mut states = self.visited_states();
+
+        // The final state is not recorded when the last wrap failed
+        let final_state = self.graph.to_state();
+        let final_index = match self.seen_states.get(&final_state) {
+            Some(&index) => index,
+            None => {
+                states.push(final_state);
+                states.len() - 1
+            }
+        };
+        let cycle_entry = self.stats.cycle_entry;
+
+        let transitions = self
+            .operations
+            .iter()
+            .enumerate()
+            .map(|(i, operation)| StateTransition {
+                from: i,
+                to: if i + 1 == self.operations.len() {
+                    final_index
+                } else {
+                    i + 1
+                },
+                operation: operation.clone(),
+                in_cycle: cycle_entry.is_some_and(|entry| i >= entry),
+            })
+            .collect();
+
+        MinimizationStateGraph {
+            states,
+            transitions,
+            cycle_entry,
+        }
+    }
+
+    /// Contacts of the current graph whose removal keeps every pair
+    /// reachable in `initial` reachable, or the reason the search stopped
+    fn redundant_contacts(
+        &self,
+        initial: &TemporalGraph,
+    ) -> Result<Vec<(VertexId, VertexId, TimeStep)>, TerminationReason> {
+        let expected = initial.reachability_graph(self.config.strict).arcs;
+        redundant_contacts(self.graph, &expected, self.config.strict, || {
+            self.interruption()
+        })
+    }
+
+    /// Reason to stop early, if the time budget ran out or the run was
+    /// cancelled
+    fn interruption(&self) -> Option<TerminationReason> {
+        if self
+            .config
+            .time_budget
+            .is_some_and(|budget| self.elapsed() >= budget)
+        {
+            return Some(TerminationReason::TimeBudgetExceeded);
         }
+        if self
+            .config
+            .cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Some(TerminationReason::Cancelled);
+        }
+        None
     }
 
     /// Check if we've seen the current graph state before
     fn has_seen_current_state(&self) -> bool {
         let current_state = self.graph.to_state();
-        self.seen_states.contains(&current_state)
+        self.seen_states.contains_key(&current_state)
     }
 
-    /// Record the current graph state
-    fn record_current_state(&mut self) {
+    /// Record the current graph state, reached after the operations so far
+    fn record_current_state(&mut self) -> GraphState {
         let current_state = self.graph.to_state();
-        self.seen_states.insert(current_state);
+        self.seen_states
+            .insert(current_state.clone(), self.operations.len());
         self.stats.states_visited += 1;
+        current_state
+    }
+
+    /// Call `event` on every observer, and on stdout when verbose
+    fn notify(&self, event: impl Fn(&dyn MinimizationObserver)) {
+        notify(&self.config, event);
+    }
+
+    /// Time spent in the run, including before the checkpoint it resumed
+    fn elapsed(&self) -> Duration {
+        self.elapsed_before + self.started.elapsed()
+    }
+
+    /// Write a checkpoint if one is scheduled at the end of `iteration`; a
+    /// failed write is reported to the observers and the run goes on.
+    ///
+    /// The first checkpoint of the run writes the whole state log, later
+    /// ones only append the states visited since.
+    fn checkpoint_if_due(&mut self, initial: &TemporalGraph, iteration: usize) {
+        let Some(schedule) = &self.config.checkpoint else {
+            return;
+        };
+        if !iteration.is_multiple_of(schedule.every) {
+            return;
+        }
+
+        let mut new_states: Vec<(usize, &GraphState)> = self
+            .seen_states
+            .iter()
+            .filter(|&(_, &index)| index >= self.logged_states)
+            .map(|(state, &index)| (index, state))
+            .collect();
+        new_states.sort_unstable_by_key(|&(index, _)| index);
+        let new_states: Vec<GraphState> = new_states
+            .into_iter()
+            .map(|(_, state)| state.clone())
+            .collect();
+
+        let header = CheckpointHeader {
+            version: CHECKPOINT_VERSION,
+            initial: GraphSnapshot::of(initial),
+            graph: GraphSnapshot::of(self.graph),
+            state_count: self.seen_states.len(),
+            operations: self.operations.clone(),
+            stats: MinimizationStats {
+                elapsed: self.elapsed(),
+                ..self.stats.clone()
+            },
+            config: CheckpointedConfig::of(&self.config),
+        };
+        let written = write_state_log(&schedule.filename, &new_states, self.logged_states > 0)
+            .and_then(|()| header.save(&schedule.filename));
+        match written {
+            Ok(()) => self.logged_states = self.seen_states.len(),
+            Err(e) => {
+                // Rewrite the whole log next time
+                self.logged_states = 0;
+                let message = format!("failed to write checkpoint: {}", e);
+                self.notify(|observer| observer.warning(&message));
+            }
+        }
     }
 
     /// Check if maximum iterations have been reached
@@ -306,35 +1002,103 @@ impl<'a> LabelMinimizer<'a> {
     }
 
     fn find_wrappable_edge(&self) -> Option<(VertexId, VertexId)> {
-        self.graph.find_wrappable_edge()
+        let candidates = self.graph.wrappable_edges();
+        if candidates.is_empty() {
+            return None;
+        }
+        let index = self
+            .config
+            .selection
+            .select_edge(&candidates, self.stats.iterations);
+        Some(candidates[index].edge)
     }
     fn find_min_incident_in_range(
         &self,
         u: VertexId,
         v: VertexId,
     ) -> Option<(VertexId, VertexId, TimeStep)> {
-        self.graph.find_min_incident_in_range(u, v)
+        let candidates = self.graph.incident_labels_in_range(u, v);
+        let tmin = candidates.first()?.2;
+        let tied: Vec<_> = candidates.into_iter().filter(|c| c.2 == tmin).collect();
+        let index = self
+            .config
+            .selection
+            .select_incident(&tied, self.stats.iterations);
+        Some(tied[index])
     }
-    fn transfer_labels(&mut self, u: VertexId, v: VertexId) -> usize {
-        let transferred = self.graph.transfer_labels_through_edge(u, v);
+}
 
-        if self.config.track_statistics {
-            self.stats.transfers_attempted += 1;
-            if transferred > 0 {
-                self.stats.transfers_successful += 1;
-            }
+/// Contacts of `graph` whose removal leaves exactly the reachable pairs
+/// `expected`, stopping early if `interruption` gives a reason to
+pub(crate) fn redundant_contacts(
+    graph: &TemporalGraph,
+    expected: &BTreeSet<(VertexId, VertexId)>,
+    strict: bool,
+    interruption: impl Fn() -> Option<TerminationReason>,
+) -> Result<Vec<(VertexId, VertexId, TimeStep)>, TerminationReason> {
+    let contacts = graph.contacts();
+
+    let mut redundant_contacts = Vec::new();
+    for &removed in &contacts {
+        if let Some(reason) = interruption() {
+            return Err(reason);
         }
+        let kept: Vec<_> = contacts.iter().copied().filter(|&c| c != removed).collect();
+        if graph.with_contacts(&kept).reachability_graph(strict).arcs == *expected {
+            redundant_contacts.push(removed);
+        }
+    }
 
-        transferred
+    Ok(redundant_contacts)
+}
+
+/// Call `event` on every observer of `config`, and on stdout when verbose
+fn notify(config: &MinimizationConfig, event: impl Fn(&dyn MinimizationObserver)) {
+    if config.verbose {
+        event(&StdoutObserver);
+    }
+    for observer in &config.observers {
+        event(observer.as_ref());
     }
 }
 
+/// Compare the reachable pairs of `graph` with the starting ones after a
+/// step of the operation on `edge` with `incident`, when auditing
+fn audit_step(
+    audit: Option<&ReachabilityAudit>,
+    graph: &TemporalGraph,
+    iteration: usize,
+    edge: (VertexId, VertexId),
+    incident: (VertexId, VertexId, TimeStep),
+    step: impl FnOnce() -> AuditedStep,
+) -> Option<ReachabilityViolation> {
+    let diff = audit?.check(graph)?;
+    Some(ReachabilityViolation {
+        iteration,
+        wrappable_edge: edge,
+        incident,
+        step: step(),
+        pairs_lost: diff.lost,
+        pairs_gained: diff.gained,
+    })
+}
+
 // Convenience function for simple usage
 impl TemporalGraph {
     /// Check if this temporal graph is label-minimal using default configuration
+    ///
+    /// Undetermined runs count as not minimal here; `label_minimality`
+    /// tells them apart.
+    #[deprecated(note = "use `label_minimality`, which reports undetermined runs")]
     pub fn is_label_minimal(&mut self) -> bool {
+        self.label_minimality().is_minimal()
+    }
+
+    /// Run the minimization algorithm with default configuration and
+    /// return its verdict
+    pub fn label_minimality(&mut self) -> MinimalityVerdict {
         let mut minimizer = LabelMinimizer::new(self);
-        minimizer.run().is_minimal
+        minimizer.run().verdict
     }
 
     /// Check if this temporal graph is label-minimal with custom configuration
@@ -354,7 +1118,19 @@ impl TemporalGraph {
     /// - tmin = min(λ(uv))
     /// - tmax = max(λ(uv))
     /// - ∃ incident edge e and t ∈ λ(e) such that tmin < t < tmax
+    ///
+    /// The lexicographically smallest such edge is returned.
     pub fn find_wrappable_edge(&self) -> Option<(VertexId, VertexId)> {
+        self.wrappable_edges()
+            .first()
+            .map(|candidate| candidate.edge)
+    }
+
+    /// All wrappable edges (see `find_wrappable_edge`), sorted
+    /// lexicographically
+    pub fn wrappable_edges(&self) -> Vec<WrappableEdge> {
+        let mut wrappable = Vec::new();
+
         // Iterate through all edges
         for ((u, v), edge) in &self.edges {
             // Check if edge has at least 2 labels
@@ -373,11 +1149,16 @@ impl TemporalGraph {
 
             // Check all incident edges (edges that share vertex u or v)
             if self.has_incident_edge_in_range(*u, *v, tmin, tmax) {
-                return Some((*u, *v));
+                wrappable.push(WrappableEdge {
+                    edge: (*u, *v),
+                    tmin,
+                    tmax,
+                });
             }
         }
 
-        None
+        wrappable.sort_unstable_by_key(|candidate| candidate.edge);
+        wrappable
     }
 
     /// Helper: Check if there exists an incident edge to {u,v} with a timestamp in (tmin, tmax)
@@ -413,18 +1194,34 @@ impl TemporalGraph {
 
         false
     }
+    /// Find the incident label with the smallest timestamp strictly between
+    /// tmin and tmax of edge {u,v}, as (neighbor, common vertex, t)
+    ///
+    /// Ties go to the smallest (neighbor, common vertex).
     pub fn find_min_incident_in_range(
         &self,
         u: VertexId,
         v: VertexId,
     ) -> Option<(VertexId, VertexId, TimeStep)> {
+        self.incident_labels_in_range(u, v).first().copied()
+    }
+
+    /// All incident labels strictly between tmin and tmax of edge {u,v}, as
+    /// (neighbor, common vertex, t), sorted by t then vertices
+    pub fn incident_labels_in_range(
+        &self,
+        u: VertexId,
+        v: VertexId,
+    ) -> Vec<(VertexId, VertexId, TimeStep)> {
         // Normalize the edge
         let (u_norm, v_norm) = if u <= v { (u, v) } else { (v, u) };
 
         // Get the edge and verify it has at least 2 timestamps
-        let edge = self.edges.get(&(u_norm, v_norm))?;
+        let Some(edge) = self.edges.get(&(u_norm, v_norm)) else {
+            return Vec::new();
+        };
         if edge.timestamps.len() < 2 {
-            return None;
+            return Vec::new();
         }
 
         // Get tmin and tmax
@@ -432,7 +1229,7 @@ impl TemporalGraph {
         let tmax = *edge.timestamps.iter().max().unwrap();
 
         if tmin >= tmax {
-            return None;
+            return Vec::new();
         }
 
         // Find all incident edges with timestamps in (tmin, tmax)
@@ -466,8 +1263,8 @@ impl TemporalGraph {
             }
         }
 
-        // Return the candidate with minimum timestamp
-        candidates.into_iter().min_by_key(|&(_, _, t)| t)
+        candidates.sort_unstable_by_key(|&(w, x, t)| (t, w, x));
+        candidates
     }
     /// Helper method to get tmin and tmax for an edge
     pub fn get_edge_time_range(&self, u: VertexId, v: VertexId) -> Option<(TimeStep, TimeStep)> {
@@ -484,16 +1281,21 @@ impl TemporalGraph {
     }
 
     pub fn transfer_labels_through_edge(&mut self, u: VertexId, v: VertexId) -> usize {
+        self.transfer_labels_with_moves(u, v).len()
+    }
+
+    /// Same as `transfer_labels_through_edge`, returning the moved labels
+    pub fn transfer_labels_with_moves(&mut self, u: VertexId, v: VertexId) -> Vec<LabelMove> {
         // Get tmin and tmax for edge {u,v}
         let (tmin, tmax) = match self.get_edge_time_range(u, v) {
             Some(range) => range,
-            None => return 0, // Edge doesn't exist
+            None => return Vec::new(), // Edge doesn't exist
         };
 
         // Find all neighbors of u (at any time)
         let neighbors_of_v = self.get_all_neighbors(v);
 
-        let mut total_transferred = 0;
+        let mut moves = Vec::new();
 
         // For each neighbor w of u (except v)
         for w in neighbors_of_v {
@@ -515,12 +1317,16 @@ impl TemporalGraph {
             // Add these timestamps to {w,v}
             for &t in &timestamps_to_transfer {
                 self.add_edge(w, u, t);
+                moves.push(LabelMove {
+                    from: (v, w),
+                    to: (w, u),
+                    time: t,
+                });
             }
-
-            total_transferred += timestamps_to_transfer.len();
         }
 
-        total_transferred
+        moves.sort_unstable();
+        moves
     }
 
     /// Get all neighbors of a vertex across all time steps
@@ -560,3 +1366,265 @@ impl TemporalGraph {
             .unwrap_or_default()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use graphviz_rust::printer::{DotPrinter, PrinterContext};
+
+    // Path 0 - 1 - 2 plus a late 0-2 contact that adds no reachable pair
+    pub(crate) fn redundant_sample() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 5);
+        g
+    }
+
+    #[test]
+    fn test_useless_label_certificate() {
+        let original = redundant_sample();
+        let mut g = original.clone_graph();
+        let result = g.is_label_minimal_with_config(MinimizationConfig::new().with_statistics());
+
+        assert_eq!(
+            result.termination_reason,
+            TerminationReason::UselessLabelFound
+        );
+        assert_eq!(result.operations.len(), 2);
+        assert_eq!(result.operations[0].wrappable_edge, (0, 1));
+        assert_eq!(result.operations[0].incident, (2, 1, 2));
+        assert!(result.operations[0].transferred.is_empty());
+        assert_eq!(
+            result.operations[1].transferred,
+            vec![LabelMove {
+                from: (0, 1),
+                to: (1, 2),
+                time: 3,
+            }]
+        );
+        assert_eq!(result.redundant_contacts(), [(1, 2, 2), (1, 2, 3)]);
+        assert_eq!(result.stats.as_ref().unwrap().useless_labels_found, 2);
+        assert_eq!(result.final_state, g.to_state());
+        assert!(result.verify_certificate(&original, true));
+    }
+
+    #[test]
+    fn test_tampered_trace_fails_replay() {
+        let original = redundant_sample();
+        let mut result = original
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new());
+
+        result.operations[0].incident = (2, 1, 4);
+        assert!(result.replay(&original).is_none());
+        assert!(!result.verify_certificate(&original, true));
+    }
+
+    #[test]
+    fn test_unbacked_claim_has_no_redundant_contact() {
+        // Label-minimal, yet the algorithm gets stuck after one wrap
+        let mut original = TemporalGraph::new();
+        original.add_edge(0, 1, 1);
+        original.add_edge(0, 1, 3);
+        original.add_edge(1, 2, 2);
+
+        let result = original
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new());
+        assert_eq!(
+            result.termination_reason,
+            TerminationReason::UselessLabelFound
+        );
+        assert_eq!(
+            result.verdict,
+            MinimalityVerdict::Undetermined {
+                reason: TerminationReason::UselessLabelFound
+            }
+        );
+        assert_eq!(result.verdict.as_bool(), None);
+        assert!(result.redundant_contacts().is_empty());
+        assert!(result.replay(&original).is_some());
+        assert!(!result.verify_certificate(&original, true));
+    }
+
+    #[test]
+    fn test_iteration_limit_is_undetermined() {
+        let mut g = redundant_sample();
+        let result =
+            g.is_label_minimal_with_config(MinimizationConfig::new().with_max_iterations(1));
+
+        assert_eq!(
+            result.verdict,
+            MinimalityVerdict::Undetermined {
+                reason: TerminationReason::MaxIterationsReached
+            }
+        );
+        assert_eq!(result.verdict.as_bool(), None);
+        #[allow(deprecated)]
+        let is_minimal = result.is_minimal;
+        assert!(!is_minimal);
+        assert!(result.redundant_contacts().is_empty());
+    }
+
+    #[test]
+    fn test_cycle_evidence() {
+        // Triangle whose wraps move the label at time 1 around and back
+        let original = cycling_sample();
+
+        let result = original
+            .clone_graph()
+            .is_label_minimal_with_config(MinimizationConfig::new());
+        let MinimalityVerdict::Minimal {
+            cycle_start,
+            cycle_length,
+        } = result.verdict
+        else {
+            panic!("expected a minimal verdict, got {:?}", result.verdict);
+        };
+        assert!(cycle_length > 0);
+        assert_eq!(cycle_start + cycle_length, result.operations.len());
+
+        // The state before the cycle is the final one
+        let mut graph = original.clone_graph();
+        for operation in &result.operations[..cycle_start] {
+            assert!(operation.apply(&mut graph));
+        }
+        assert_eq!(graph.to_state(), result.final_state);
+        assert_eq!(result.verdict.as_bool(), Some(true));
+    }
+
+    #[test]
+    fn test_state_graph_marks_cycle() {
+        let mut g = cycling_sample();
+
+        let config = MinimizationConfig::new()
+            .with_statistics()
+            .with_state_graph();
+        let result = g.is_label_minimal_with_config(config);
+        let stats = result.stats.as_ref().unwrap();
+        let states = result.state_graph.as_ref().unwrap();
+
+        assert_eq!(states.transitions.len(), result.operations.len());
+        assert_eq!(states.states.len(), stats.states_visited);
+        assert_eq!(states.cycle_entry, stats.cycle_entry);
+        assert_eq!(states.cycle_length(), stats.cycle_length);
+
+        // The last transition closes the cycle on its entry state
+        let entry = states.cycle_entry.unwrap();
+        let last = states.transitions.last().unwrap();
+        assert_eq!(last.to, entry);
+        assert_eq!(states.states[entry], result.final_state);
+        assert!(
+            states
+                .transitions
+                .iter()
+                .all(|step| step.in_cycle == (step.from >= entry))
+        );
+
+        let dot = states.to_dot().print(&mut PrinterContext::default());
+        assert!(dot.contains("red"));
+    }
+
+    #[test]
+    fn test_state_graph_json_round_trip() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 5);
+
+        let result = g.is_label_minimal_with_config(MinimizationConfig::new().with_state_graph());
+        let states = result.state_graph.unwrap();
+        assert_eq!(states.cycle_entry, None);
+        assert_eq!(states.states.len(), 3);
+
+        let parsed = MinimizationStateGraph::from_json(&states.to_json()).unwrap();
+        assert_eq!(parsed, states);
+        assert!(MinimizationStateGraph::from_json("{}").is_err());
+    }
+
+    // Triangle cycling through several states
+    pub(crate) fn cycling_sample() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 3, 1);
+        g.add_edge(0, 3, 5);
+        g.add_edge(2, 3, 5);
+        g.add_edge(0, 2, 3);
+        g
+    }
+
+    #[test]
+    fn test_resource_limits() {
+        let config = MinimizationConfig::new().with_statistics();
+
+        let result = cycling_sample()
+            .is_label_minimal_with_config(config.clone().with_time_budget(Duration::ZERO));
+        assert_eq!(
+            result.termination_reason,
+            TerminationReason::TimeBudgetExceeded
+        );
+        assert!(result.verdict.is_undetermined());
+
+        let result =
+            cycling_sample().is_label_minimal_with_config(config.clone().with_max_stored_states(1));
+        assert_eq!(
+            result.termination_reason,
+            TerminationReason::StateLimitReached
+        );
+        let stats = result.stats.unwrap();
+        assert_eq!(stats.states_visited, 1);
+        assert_eq!(result.operations.len(), 1);
+
+        // The last operation leads back to a stored state
+        let unbounded = cycling_sample().is_label_minimal_with_config(config);
+        assert!(unbounded.verdict.is_minimal());
+        let stats = unbounded.stats.unwrap();
+        assert_eq!(stats.states_visited, unbounded.operations.len());
+    }
+
+    #[test]
+    fn test_cancellation_from_another_thread() {
+        let token = CancellationToken::new();
+        let remote = token.clone();
+        std::thread::spawn(move || remote.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+
+        let result = cycling_sample()
+            .is_label_minimal_with_config(MinimizationConfig::new().with_cancellation(token));
+        assert_eq!(result.termination_reason, TerminationReason::Cancelled);
+        assert!(result.operations.is_empty());
+    }
+
+    #[derive(Debug)]
+    struct CancelWhenStuck(CancellationToken);
+
+    impl MinimizationObserver for CancelWhenStuck {
+        fn no_wrappable_edge(&self) {
+            self.0.cancel();
+        }
+    }
+
+    #[test]
+    fn test_redundant_contact_search_can_be_skipped_or_cancelled() {
+        let skipped = redundant_sample()
+            .is_label_minimal_with_config(MinimizationConfig::new().without_redundant_contacts());
+        assert_eq!(
+            skipped.termination_reason,
+            TerminationReason::UselessLabelFound
+        );
+        assert!(skipped.verdict.is_undetermined());
+
+        // Cancelled once the run is stuck, before the search
+        let token = CancellationToken::new();
+        let cancelled = redundant_sample().is_label_minimal_with_config(
+            MinimizationConfig::new()
+                .with_cancellation(token.clone())
+                .with_observer(Arc::new(CancelWhenStuck(token))),
+        );
+        assert_eq!(cancelled.termination_reason, TerminationReason::Cancelled);
+        assert!(cancelled.verdict.is_undetermined());
+        assert_eq!(cancelled.operations.len(), 2);
+    }
+}
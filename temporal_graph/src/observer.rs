@@ -0,0 +1,304 @@
+use crate::{GraphState, LabelMove, TemporalGraph, TerminationReason, TimeStep, VertexId};
+use std::fmt::Debug;
+use std::sync::Mutex;
+
+/// Callbacks invoked by `LabelMinimizer` as a run progresses.
+///
+/// Every method does nothing by default. Observers are shared through the
+/// configuration, so they take `&self` and must be thread-safe.
+pub trait MinimizationObserver: Debug + Send + Sync {
+    /// Before the first iteration, with the input graph
+    fn run_started(&self, _graph: &TemporalGraph) {}
+
+    /// At the start of iteration `iteration` (counted from 1)
+    fn iteration_started(&self, _iteration: usize) {}
+
+    /// A wrappable edge `{u, v}` was chosen
+    fn wrappable_edge_found(&self, _u: VertexId, _v: VertexId) {}
+
+    /// No wrappable edge is left
+    fn no_wrappable_edge(&self) {}
+
+    /// Labels moved through edge `through` after choosing the incident
+    /// label `(w, x, t)`
+    fn labels_transferred(
+        &self,
+        _incident: (VertexId, VertexId, TimeStep),
+        _through: (VertexId, VertexId),
+        _moves: &[LabelMove],
+    ) {
+    }
+
+    /// The `tmin` label of the wrappable edge was moved, giving `graph`
+    fn label_moved(&self, _label: LabelMove, _graph: &TemporalGraph) {}
+
+    /// A new state was recorded, `states_visited` in total
+    fn state_recorded(&self, _state: &GraphState, _states_visited: usize) {}
+
+    /// Something unexpected stopped the run
+    fn warning(&self, _message: &str) {}
+
+    /// The run ended, leaving `graph`
+    fn terminated(&self, _reason: &TerminationReason, _graph: &TemporalGraph) {}
+}
+
+/// Observer printing the progress of a run, as `MinimizationConfig::verbose`
+/// does.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutObserver;
+
+impl MinimizationObserver for StdoutObserver {
+    fn run_started(&self, graph: &TemporalGraph) {
+        println!("Starting label minimization algorithm");
+        println!();
+        graph.print_state();
+    }
+
+    fn iteration_started(&self, iteration: usize) {
+        println!("\n=== Iteration {} ===", iteration);
+    }
+
+    fn wrappable_edge_found(&self, u: VertexId, v: VertexId) {
+        println!("Found wrappable edge: ({}, {})", u, v);
+    }
+
+    fn no_wrappable_edge(&self) {
+        println!("No wrappable edge found - checking if useless label detected");
+    }
+
+    fn labels_transferred(
+        &self,
+        incident: (VertexId, VertexId, TimeStep),
+        through: (VertexId, VertexId),
+        moves: &[LabelMove],
+    ) {
+        let (w, x, t) = incident;
+        println!(
+            "Found incident: w={} (neighbor), x={} (common vertex), t={}",
+            w, x, t
+        );
+        println!("Other endpoint of e: {}", through.1);
+        println!(
+            "Transferring labels through edge ({}, {})",
+            through.0, through.1
+        );
+        println!("Transferred {} labels", moves.len());
+    }
+
+    fn label_moved(&self, label: LabelMove, graph: &TemporalGraph) {
+        println!(
+            "Removing tmin={} from edge ({}, {})",
+            label.time, label.from.0, label.from.1
+        );
+        println!(
+            "Adding tmin={} to edge ({}, {})",
+            label.time, label.to.1, label.to.0
+        );
+        println!();
+        graph.print_state();
+    }
+
+    fn state_recorded(&self, _state: &GraphState, states_visited: usize) {
+        println!("New state recorded (total states: {})", states_visited);
+    }
+
+    fn warning(&self, message: &str) {
+        println!("Warning: {}", message);
+    }
+
+    fn terminated(&self, reason: &TerminationReason, graph: &TemporalGraph) {
+        match reason {
+            TerminationReason::CycleDetected => println!("Cycle detected! Graph is minimal"),
+            TerminationReason::UselessLabelFound => {
+                println!("Algorithm terminated");
+                println!("Graph is not minimal (no cycling)");
+                println!();
+                graph.print_state();
+            }
+            TerminationReason::MaxIterationsReached => println!("Max iterations reached"),
+            TerminationReason::TimeBudgetExceeded => println!("Time budget exceeded"),
+            TerminationReason::StateLimitReached => println!("State limit reached"),
+            TerminationReason::Cancelled => println!("Run cancelled"),
+            TerminationReason::ReachabilityViolated => println!("Reachability violated"),
+        }
+    }
+}
+
+/// Event recorded by `CollectingObserver`, one per callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MinimizationEvent {
+    RunStarted(GraphState),
+    IterationStarted(usize),
+    WrappableEdgeFound(VertexId, VertexId),
+    NoWrappableEdge,
+    LabelsTransferred {
+        incident: (VertexId, VertexId, TimeStep),
+        through: (VertexId, VertexId),
+        moves: Vec<LabelMove>,
+    },
+    LabelMoved(LabelMove),
+    StateRecorded {
+        state: GraphState,
+        states_visited: usize,
+    },
+    Warning(String),
+    Terminated {
+        reason: TerminationReason,
+        state: GraphState,
+    },
+}
+
+/// Observer keeping every event in memory, for tests and GUIs.
+#[derive(Debug, Default)]
+pub struct CollectingObserver {
+    events: Mutex<Vec<MinimizationEvent>>,
+}
+
+impl CollectingObserver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Events received so far, in order
+    pub fn events(&self) -> Vec<MinimizationEvent> {
+        self.events.lock().expect("observer lock poisoned").clone()
+    }
+
+    /// Forget the events received so far
+    pub fn clear(&self) {
+        self.events.lock().expect("observer lock poisoned").clear();
+    }
+
+    fn push(&self, event: MinimizationEvent) {
+        self.events
+            .lock()
+            .expect("observer lock poisoned")
+            .push(event);
+    }
+}
+
+impl MinimizationObserver for CollectingObserver {
+    fn run_started(&self, graph: &TemporalGraph) {
+        self.push(MinimizationEvent::RunStarted(graph.to_state()));
+    }
+
+    fn iteration_started(&self, iteration: usize) {
+        self.push(MinimizationEvent::IterationStarted(iteration));
+    }
+
+    fn wrappable_edge_found(&self, u: VertexId, v: VertexId) {
+        self.push(MinimizationEvent::WrappableEdgeFound(u, v));
+    }
+
+    fn no_wrappable_edge(&self) {
+        self.push(MinimizationEvent::NoWrappableEdge);
+    }
+
+    fn labels_transferred(
+        &self,
+        incident: (VertexId, VertexId, TimeStep),
+        through: (VertexId, VertexId),
+        moves: &[LabelMove],
+    ) {
+        self.push(MinimizationEvent::LabelsTransferred {
+            incident,
+            through,
+            moves: moves.to_vec(),
+        });
+    }
+
+    fn label_moved(&self, label: LabelMove, _graph: &TemporalGraph) {
+        self.push(MinimizationEvent::LabelMoved(label));
+    }
+
+    fn state_recorded(&self, state: &GraphState, states_visited: usize) {
+        self.push(MinimizationEvent::StateRecorded {
+            state: state.clone(),
+            states_visited,
+        });
+    }
+
+    fn warning(&self, message: &str) {
+        self.push(MinimizationEvent::Warning(message.to_string()));
+    }
+
+    fn terminated(&self, reason: &TerminationReason, graph: &TemporalGraph) {
+        self.push(MinimizationEvent::Terminated {
+            reason: reason.clone(),
+            state: graph.to_state(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MinimizationConfig;
+    use crate::minimization::tests::cycling_sample;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_collected_events_follow_the_run() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 1, 3);
+        g.add_edge(1, 2, 2);
+        g.add_edge(0, 2, 5);
+
+        let observer = Arc::new(CollectingObserver::new());
+        let config = MinimizationConfig::new().with_observer(observer.clone());
+        let result = g.is_label_minimal_with_config(config);
+        let events = observer.events();
+
+        assert!(matches!(events[0], MinimizationEvent::RunStarted(_)));
+        assert_eq!(events[1], MinimizationEvent::IterationStarted(1));
+        assert_eq!(events[2], MinimizationEvent::WrappableEdgeFound(0, 1));
+        assert_eq!(
+            events[4],
+            MinimizationEvent::LabelMoved(result.operations[0].wrapped)
+        );
+        assert_eq!(
+            events.last(),
+            Some(&MinimizationEvent::Terminated {
+                reason: TerminationReason::UselessLabelFound,
+                state: result.final_state.clone(),
+            })
+        );
+
+        let moved = events
+            .iter()
+            .filter(|e| matches!(e, MinimizationEvent::LabelMoved(_)))
+            .count();
+        assert_eq!(moved, result.operations.len());
+        assert!(events.contains(&MinimizationEvent::NoWrappableEdge));
+    }
+
+    #[test]
+    fn test_cycle_events() {
+        let mut g = cycling_sample();
+
+        let observer = Arc::new(CollectingObserver::new());
+        let config = MinimizationConfig::new()
+            .with_statistics()
+            .with_observer(observer.clone());
+        let result = g.is_label_minimal_with_config(config);
+        let events = observer.events();
+
+        // Every new state but the input one is announced
+        let recorded = events
+            .iter()
+            .filter(|e| matches!(e, MinimizationEvent::StateRecorded { .. }))
+            .count();
+        assert_eq!(recorded + 1, result.stats.unwrap().states_visited);
+        assert!(matches!(
+            events.last(),
+            Some(MinimizationEvent::Terminated {
+                reason: TerminationReason::CycleDetected,
+                ..
+            })
+        ));
+
+        observer.clear();
+        assert!(observer.events().is_empty());
+    }
+}
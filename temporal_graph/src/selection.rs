@@ -0,0 +1,214 @@
+use crate::{TimeStep, VertexId};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Edge with at least two labels and an incident label strictly between
+/// its smallest and largest ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrappableEdge {
+    /// Endpoints, smallest first.
+    pub edge: (VertexId, VertexId),
+    pub tmin: TimeStep,
+    pub tmax: TimeStep,
+}
+
+impl WrappableEdge {
+    /// Time between the smallest and largest labels
+    pub fn span(&self) -> TimeStep {
+        self.tmax - self.tmin
+    }
+}
+
+/// Choices made by `LabelMinimizer` at each iteration.
+///
+/// Implementations must be deterministic functions of their arguments so
+/// that runs can be reproduced.
+pub trait SelectionStrategy: Debug + Send + Sync {
+    /// Index of the edge to wrap in `candidates`, which is non-empty and
+    /// sorted lexicographically
+    fn select_edge(&self, candidates: &[WrappableEdge], iteration: usize) -> usize;
+
+    /// Index of the incident label to use in `candidates`, the non-empty
+    /// list of `(w, x, t)` sharing the smallest timestamp, sorted; the first
+    /// one by default
+    fn select_incident(
+        &self,
+        _candidates: &[(VertexId, VertexId, TimeStep)],
+        _iteration: usize,
+    ) -> usize {
+        0
+    }
+
+    /// The built-in policy this strategy is, if any, so that checkpoints
+    /// can record it
+    fn policy(&self) -> Option<SelectionPolicy> {
+        None
+    }
+}
+
+/// Built-in selection strategies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SelectionPolicy {
+    /// Smallest edge, then smallest incident label.
+    Lexicographic,
+
+    /// Edge with the smallest `tmin`, ties broken lexicographically.
+    SmallestTmin,
+
+    /// Edge with the largest `tmax - tmin`, ties broken lexicographically.
+    LargestSpan,
+
+    /// Uniform choices drawn from a ChaCha generator seeded with the given
+    /// seed and the iteration number, for edges and tied incident labels.
+    SeededRandom(u64),
+}
+
+impl SelectionStrategy for SelectionPolicy {
+    fn select_edge(&self, candidates: &[WrappableEdge], iteration: usize) -> usize {
+        let best = |key: &dyn Fn(&WrappableEdge) -> TimeStep| {
+            (0..candidates.len())
+                .min_by_key(|&i| key(&candidates[i]))
+                .unwrap_or(0)
+        };
+
+        match *self {
+            SelectionPolicy::Lexicographic => 0,
+            SelectionPolicy::SmallestTmin => best(&|candidate| candidate.tmin),
+            SelectionPolicy::LargestSpan => best(&|candidate| -candidate.span()),
+            SelectionPolicy::SeededRandom(seed) => {
+                seeded_index(seed, iteration, 0, candidates.len())
+            }
+        }
+    }
+
+    fn select_incident(
+        &self,
+        candidates: &[(VertexId, VertexId, TimeStep)],
+        iteration: usize,
+    ) -> usize {
+        match *self {
+            SelectionPolicy::SeededRandom(seed) => {
+                seeded_index(seed, iteration, 1, candidates.len())
+            }
+            _ => 0,
+        }
+    }
+
+    fn policy(&self) -> Option<SelectionPolicy> {
+        Some(*self)
+    }
+}
+
+/// Uniform index below `len`, from a generator keyed by seed, iteration and
+/// stream so that edge and label choices are independent
+fn seeded_index(seed: u64, iteration: usize, stream: u64, len: usize) -> usize {
+    let mut rng =
+        ChaCha8Rng::seed_from_u64(seed ^ (iteration as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    rng.set_stream(stream);
+    rng.gen_range(0..len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MinimizationConfig, TemporalGraph};
+
+    fn candidates() -> Vec<WrappableEdge> {
+        vec![
+            WrappableEdge {
+                edge: (0, 1),
+                tmin: 3,
+                tmax: 5,
+            },
+            WrappableEdge {
+                edge: (1, 2),
+                tmin: 1,
+                tmax: 4,
+            },
+            WrappableEdge {
+                edge: (2, 3),
+                tmin: 2,
+                tmax: 9,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_builtin_policies() {
+        let candidates = candidates();
+        assert_eq!(
+            SelectionPolicy::Lexicographic.select_edge(&candidates, 1),
+            0
+        );
+        assert_eq!(SelectionPolicy::SmallestTmin.select_edge(&candidates, 1), 1);
+        assert_eq!(SelectionPolicy::LargestSpan.select_edge(&candidates, 1), 2);
+
+        let random = SelectionPolicy::SeededRandom(7);
+        for iteration in 0..20 {
+            let index = random.select_edge(&candidates, iteration);
+            assert!(index < candidates.len());
+            assert_eq!(index, random.select_edge(&candidates, iteration));
+        }
+    }
+
+    #[test]
+    fn test_graph_candidates_are_sorted() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(2, 3, 1);
+        g.add_edge(2, 3, 6);
+        g.add_edge(0, 1, 2);
+        g.add_edge(0, 1, 4);
+        g.add_edge(1, 2, 3);
+        g.add_edge(0, 2, 3);
+
+        let edges: Vec<_> = g.wrappable_edges().iter().map(|c| c.edge).collect();
+        assert_eq!(edges, vec![(0, 1), (2, 3)]);
+        assert_eq!(g.find_wrappable_edge(), Some((0, 1)));
+
+        // Both labels at time 3 tie on {0, 1}
+        assert_eq!(g.incident_labels_in_range(0, 1), vec![(2, 0, 3), (2, 1, 3)]);
+        assert_eq!(g.find_min_incident_in_range(0, 1), Some((2, 0, 3)));
+    }
+
+    #[test]
+    fn test_runs_are_reproducible() {
+        let mut g = TemporalGraph::new();
+        for (u, v, t) in [
+            (0, 1, 7),
+            (1, 2, 1),
+            (1, 2, 4),
+            (2, 3, 2),
+            (2, 3, 5),
+            (0, 3, 3),
+            (0, 3, 8),
+        ] {
+            g.add_edge(u, v, t);
+        }
+
+        for policy in [
+            SelectionPolicy::Lexicographic,
+            SelectionPolicy::SmallestTmin,
+            SelectionPolicy::LargestSpan,
+            SelectionPolicy::SeededRandom(3),
+        ] {
+            let config = MinimizationConfig::new().with_selection(policy);
+            let first = g.clone_graph().is_label_minimal_with_config(config.clone());
+            let second = g.clone_graph().is_label_minimal_with_config(config);
+            assert_eq!(first.operations, second.operations, "{policy:?}");
+            assert_eq!(first.verdict, second.verdict, "{policy:?}");
+        }
+
+        // {1, 2}, {2, 3} and {0, 3} are wrappable at the start: {1, 2} has
+        // the smallest tmin and {0, 3} the largest span
+        let smallest = g.clone_graph().is_label_minimal_with_config(
+            MinimizationConfig::new().with_selection(SelectionPolicy::SmallestTmin),
+        );
+        let widest = g.clone_graph().is_label_minimal_with_config(
+            MinimizationConfig::new().with_selection(SelectionPolicy::LargestSpan),
+        );
+        assert_eq!(smallest.operations[0].wrappable_edge, (1, 2));
+        assert_eq!(widest.operations[0].wrappable_edge, (0, 3));
+    }
+}
@@ -0,0 +1,432 @@
+use crate::{Journey, TemporalGraph, TimeStep, VertexId};
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Separator and disjoint-journey numbers between two vertices, with
+/// witnesses.
+///
+/// In static graphs both numbers are equal (Menger's theorem). Temporal
+/// graphs may need strictly more separator vertices than there are
+/// vertex-disjoint journeys, which `menger_gap` measures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemporalMengerReport {
+    pub source: VertexId,
+    pub target: VertexId,
+    /// Minimum set of intermediate vertices hitting every journey, or `None`
+    /// if no such set exists (direct contact between source and target).
+    pub vertex_separator: Option<Vec<VertexId>>,
+    /// Minimum set of contacts hitting every journey.
+    pub contact_separator: Vec<(VertexId, VertexId, TimeStep)>,
+    /// Maximum set of journeys sharing no vertex besides source and target.
+    pub disjoint_journeys: Vec<Journey>,
+}
+
+impl TemporalMengerReport {
+    /// Size of the minimum vertex separator
+    pub fn vertex_separator_size(&self) -> Option<usize> {
+        self.vertex_separator.as_ref().map(Vec::len)
+    }
+
+    /// Number of pairwise vertex-disjoint journeys
+    pub fn disjoint_journey_count(&self) -> usize {
+        self.disjoint_journeys.len()
+    }
+
+    /// Difference between the separator size and the number of disjoint
+    /// journeys, `None` when no vertex separator exists
+    pub fn menger_gap(&self) -> Option<usize> {
+        self.vertex_separator_size()
+            .map(|size| size - self.disjoint_journey_count())
+    }
+}
+
+impl TemporalGraph {
+    /// Compute a minimum temporal vertex separator between `source` and
+    /// `target` by exhaustive search.
+    ///
+    /// A separator is a set of vertices other than `source` and `target`
+    /// whose removal leaves no journey from `source` to `target`. Subsets
+    /// are tried in order of increasing size, restricted to vertices lying
+    /// on some journey; the number of subsets grows exponentially, so only
+    /// use this on small graphs.
+    ///
+    /// Returns `None` if `source == target` or if they share a contact,
+    /// since no vertex removal can then separate them. The separator is
+    /// sorted, and empty if `target` is already unreachable.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn minimum_temporal_vertex_separator(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+    ) -> Option<Vec<VertexId>> {
+        if source == target || self.edge_times(source, target).is_some() {
+            return None;
+        }
+
+        let candidates: Vec<VertexId> = self
+            .journey_contacts(source, target, strict)
+            .into_iter()
+            .flat_map(|(u, v, _)| [u, v])
+            .filter(|&w| w != source && w != target)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        for size in 0..=candidates.len() {
+            let found = candidates
+                .iter()
+                .copied()
+                .combinations(size)
+                .find(|removed| {
+                    let kept: HashSet<VertexId> = self
+                        .vertices
+                        .iter()
+                        .copied()
+                        .filter(|w| !removed.contains(w))
+                        .collect();
+                    !self
+                        .induced_subgraph(&kept)
+                        .reachable_from(source, strict)
+                        .contains(&target)
+                });
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        // Unreachable: removing every candidate leaves only source and target
+        None
+    }
+
+    /// Compute a minimum set of contacts whose removal leaves no journey
+    /// from `source` to `target`, by exhaustive search.
+    ///
+    /// Only contacts lying on some journey are considered, in subsets of
+    /// increasing size. Returns an empty set if `target` is already
+    /// unreachable or `source == target`.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn minimum_temporal_contact_separator(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+    ) -> Vec<(VertexId, VertexId, TimeStep)> {
+        if source == target {
+            return Vec::new();
+        }
+
+        let all = self.contacts();
+        let candidates = self.journey_contacts(source, target, strict);
+
+        for size in 0..=candidates.len() {
+            let found = candidates
+                .iter()
+                .copied()
+                .combinations(size)
+                .find(|removed| {
+                    let kept: Vec<_> = all
+                        .iter()
+                        .copied()
+                        .filter(|c| !removed.contains(c))
+                        .collect();
+                    !self
+                        .with_contacts(&kept)
+                        .reachable_from(source, strict)
+                        .contains(&target)
+                });
+
+            if let Some(separator) = found {
+                return separator;
+            }
+        }
+
+        // Unreachable: removing every candidate removes every journey
+        candidates
+    }
+
+    /// Compute a maximum set of journeys from `source` to `target` that
+    /// pairwise share no vertex besides the endpoints, by exhaustive search.
+    ///
+    /// Every journey visiting each vertex at most once is enumerated, and
+    /// only one witness is kept per inclusion-minimal set of intermediate
+    /// vertices, since a journey can always be swapped for one using a
+    /// subset of its vertices. A maximum packing of those sets is then found
+    /// by branch and bound. Journeys over a direct contact have no
+    /// intermediate vertex and are counted once.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn maximum_vertex_disjoint_journeys(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+    ) -> Vec<Journey> {
+        if source == target || !self.has_vertex(source) || !self.has_vertex(target) {
+            return Vec::new();
+        }
+
+        let mut witnesses: HashMap<BTreeSet<VertexId>, Journey> = HashMap::new();
+        let mut search = PathJourneySearch {
+            contact_lists: self.contact_lists(),
+            latest_departure: self.reaching_to(target, strict, None),
+            target,
+            strict,
+            visited: HashSet::from([source]),
+            hops: Vec::new(),
+        };
+        search.run(source, TimeStep::MIN, &mut |hops| {
+            let inner: BTreeSet<VertexId> = hops[1..].iter().map(|&(u, _, _)| u).collect();
+            let shorter = witnesses
+                .get(&inner)
+                .is_none_or(|known| hops.len() < known.hop_count());
+            if shorter {
+                witnesses.insert(
+                    inner,
+                    Journey {
+                        hops: hops.to_vec(),
+                    },
+                );
+            }
+        });
+
+        let mut sets: Vec<BTreeSet<VertexId>> = witnesses.keys().cloned().collect();
+        sets.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        let minimal: Vec<BTreeSet<VertexId>> = sets
+            .iter()
+            .filter(|set| {
+                !sets
+                    .iter()
+                    .any(|other| !other.is_empty() && other != *set && other.is_subset(set))
+            })
+            .cloned()
+            .collect();
+
+        let mut best = Vec::new();
+        pack_disjoint(&minimal, 0, &mut Vec::new(), &mut HashSet::new(), &mut best);
+
+        best.into_iter()
+            .map(|i| {
+                witnesses
+                    .remove(&minimal[i])
+                    .expect("packed sets have witnesses")
+            })
+            .collect()
+    }
+
+    /// Compare the minimum separators with the maximum number of
+    /// vertex-disjoint journeys between `source` and `target`.
+    ///
+    /// Runs the three exact solvers above; only use it on small graphs.
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    pub fn temporal_menger(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+    ) -> TemporalMengerReport {
+        TemporalMengerReport {
+            source,
+            target,
+            vertex_separator: self.minimum_temporal_vertex_separator(source, target, strict),
+            contact_separator: self.minimum_temporal_contact_separator(source, target, strict),
+            disjoint_journeys: self.maximum_vertex_disjoint_journeys(source, target, strict),
+        }
+    }
+
+    /// Contacts `(u, v, t)` usable in at least one direction by a journey
+    /// from `source` to `target`, in `contacts()` order
+    fn journey_contacts(
+        &self,
+        source: VertexId,
+        target: VertexId,
+        strict: bool,
+    ) -> Vec<(VertexId, VertexId, TimeStep)> {
+        let arrival = self.earliest_arrival_times(source, strict);
+        let departure = self.reaching_to(target, strict, None);
+
+        let arrives_before = |a: VertexId, t: TimeStep| {
+            a == source
+                || arrival
+                    .get(&a)
+                    .is_some_and(|&at| if strict { at < t } else { at <= t })
+        };
+        let leaves_after = |b: VertexId, t: TimeStep| {
+            b == target
+                || departure
+                    .get(&b)
+                    .is_some_and(|&lt| if strict { lt > t } else { lt >= t })
+        };
+
+        self.contacts()
+            .into_iter()
+            .filter(|&(u, v, t)| {
+                (arrives_before(u, t) && leaves_after(v, t))
+                    || (arrives_before(v, t) && leaves_after(u, t))
+            })
+            .collect()
+    }
+}
+
+/// Depth-first enumeration of journeys that never revisit a vertex
+struct PathJourneySearch {
+    contact_lists: HashMap<VertexId, Vec<(VertexId, TimeStep)>>,
+    latest_departure: HashMap<VertexId, TimeStep>,
+    target: VertexId,
+    strict: bool,
+    visited: HashSet<VertexId>,
+    hops: Vec<(VertexId, VertexId, TimeStep)>,
+}
+
+impl PathJourneySearch {
+    fn run(
+        &mut self,
+        current: VertexId,
+        last: TimeStep,
+        found: &mut impl FnMut(&[(VertexId, VertexId, TimeStep)]),
+    ) {
+        let next_hops: Vec<(VertexId, TimeStep)> = self
+            .contact_lists
+            .get(&current)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|&(w, t)| {
+                let usable =
+                    last == TimeStep::MIN || if self.strict { t > last } else { t >= last };
+                let useful = w == self.target
+                    || self
+                        .latest_departure
+                        .get(&w)
+                        .is_some_and(|&next| if self.strict { t < next } else { t <= next });
+                usable && useful && !self.visited.contains(&w)
+            })
+            .collect();
+
+        for (w, t) in next_hops {
+            self.hops.push((current, w, t));
+            if w == self.target {
+                found(&self.hops);
+            } else {
+                self.visited.insert(w);
+                self.run(w, t, found);
+                self.visited.remove(&w);
+            }
+            self.hops.pop();
+        }
+    }
+}
+
+/// Branch and bound for a maximum family of pairwise disjoint sets; the
+/// empty set is disjoint from everything and is simply taken
+fn pack_disjoint(
+    sets: &[BTreeSet<VertexId>],
+    index: usize,
+    chosen: &mut Vec<usize>,
+    used: &mut HashSet<VertexId>,
+    best: &mut Vec<usize>,
+) {
+    if chosen.len() > best.len() {
+        *best = chosen.clone();
+    }
+    if index == sets.len() || chosen.len() + (sets.len() - index) <= best.len() {
+        return;
+    }
+
+    let set = &sets[index];
+    if set.iter().all(|w| !used.contains(w)) {
+        chosen.push(index);
+        used.extend(set.iter().copied());
+        pack_disjoint(sets, index + 1, chosen, used, best);
+        for w in set {
+            used.remove(w);
+        }
+        chosen.pop();
+    }
+
+    pack_disjoint(sets, index + 1, chosen, used, best);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Two routes 0 -> 1 -> 3 and 0 -> 2 -> 3
+    fn diamond() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 3, 2);
+        g.add_edge(0, 2, 1);
+        g.add_edge(2, 3, 2);
+        g
+    }
+
+    #[test]
+    fn test_diamond_satisfies_menger() {
+        let report = diamond().temporal_menger(0, 3, true);
+
+        assert_eq!(report.vertex_separator, Some(vec![1, 2]));
+        assert_eq!(report.contact_separator.len(), 2);
+        assert_eq!(report.disjoint_journey_count(), 2);
+        assert_eq!(report.menger_gap(), Some(0));
+    }
+
+    #[test]
+    fn test_separator_ignores_useless_vertices() {
+        // 1 -> 3 is used too late for 0 to take it
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 3, 2);
+        g.add_edge(0, 2, 1);
+        g.add_edge(2, 3, 2);
+
+        assert_eq!(
+            g.minimum_temporal_vertex_separator(0, 3, true),
+            Some(vec![2])
+        );
+        assert_eq!(g.minimum_temporal_contact_separator(0, 3, true).len(), 1);
+        assert_eq!(g.maximum_vertex_disjoint_journeys(0, 3, true).len(), 1);
+    }
+
+    #[test]
+    fn test_adjacent_vertices_cannot_be_separated() {
+        let mut g = diamond();
+        g.add_edge(0, 3, 7);
+
+        let report = g.temporal_menger(0, 3, true);
+        assert_eq!(report.vertex_separator, None);
+        assert_eq!(report.menger_gap(), None);
+        assert_eq!(report.contact_separator.len(), 3);
+        assert_eq!(report.disjoint_journey_count(), 3);
+    }
+
+    #[test]
+    fn test_menger_gap() {
+        // Inner vertex sets of the journeys 0 -> 5: {2, 4}, {3, 4} and
+        // {1, 2, 3} pairwise intersect, but no single vertex hits all three
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 4, 0);
+        g.add_edge(4, 2, 4);
+        g.add_edge(2, 5, 8);
+        g.add_edge(4, 3, 4);
+        g.add_edge(3, 5, 5);
+        g.add_edge(0, 1, 5);
+        g.add_edge(1, 3, 6);
+        g.add_edge(3, 2, 7);
+
+        let report = g.temporal_menger(0, 5, true);
+        assert_eq!(report.disjoint_journey_count(), 1);
+        assert_eq!(report.vertex_separator_size(), Some(2));
+        assert_eq!(report.menger_gap(), Some(1));
+        // Contacts 0-4 and 0-1 cut everything
+        assert_eq!(report.contact_separator, vec![(0, 4, 0), (0, 1, 5)]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+impl TemporalGraph {
+    /// Compute a minimum temporal spanner by exhaustive search.
+    ///
+    /// A temporal spanner is a subset of the contacts that keeps the graph
+    /// temporally connected. Subsets are tried in order of increasing size,
+    /// starting from `n - 1` contacts (the underlying graph must stay
+    /// connected), so the first connected subset found has minimum
+    /// cardinality. The number of subsets grows exponentially: only use this
+    /// on small graphs, and fall back to `greedy_temporal_spanner` otherwise.
+    ///
+    /// Returns `None` if the graph itself is not temporally connected.
+    /// Otherwise the kept contacts are returned as a new graph over the same
+    /// vertex set.
+    ///
+    /// # Parameters
+    /// - `strict`: forwarded to `is_temporally_connected`.
+    pub fn minimum_temporal_spanner(&self, strict: bool) -> Option<TemporalGraph> {
+        if !self.is_temporally_connected(strict) {
+            return None;
+        }
+
+        let contacts = self.contacts();
+        let lower_bound = self.vertex_count().saturating_sub(1);
+
+        for size in lower_bound..=contacts.len() {
+            let found = contacts
+                .iter()
+                .copied()
+                .combinations(size)
+                .map(|subset| self.with_contacts(&subset))
+                .find(|candidate| candidate.is_temporally_connected(strict));
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        // Unreachable: the full contact set is temporally connected
+        None
+    }
+
+    /// Compute an inclusion-minimal temporal spanner greedily.
+    ///
+    /// Starting from all contacts, each contact is dropped in turn if the
+    /// remaining ones still keep the graph temporally connected. Contacts on
+    /// edges with many labels are tried first, latest label first, since
+    /// those are the most likely to be redundant. The result cannot lose any
+    /// further contact, but it is not necessarily of minimum size.
+    ///
+    /// Returns `None` if the graph itself is not temporally connected.
+    ///
+    /// # Parameters
+    /// - `strict`: forwarded to the reachability checks.
+    pub fn greedy_temporal_spanner(&self, strict: bool) -> Option<TemporalGraph> {
+        if !self.is_temporally_connected(strict) {
+            return None;
+        }
+
+        let labels_per_edge: HashMap<(VertexId, VertexId), usize> = self
+            .edges
+            .iter()
+            .map(|(key, edge)| (*key, edge.timestamps.len()))
+            .collect();
+
+        let mut order = self.contacts();
+        order.sort_by_key(|&(u, v, t)| {
+            (
+                std::cmp::Reverse(labels_per_edge[&(u, v)]),
+                std::cmp::Reverse(t),
+                u,
+                v,
+            )
+        });
+
+        let mut spanner = self.clone_graph();
+        for (u, v, t) in order {
+            spanner.remove_edge_timestamp(u, v, t);
+            if !spanner.is_temporally_connected(strict) {
+                spanner.add_edge(u, v, t);
+            }
+        }
+
+        Some(spanner)
+    }
+
+    /// Build a graph over the same vertices and vertex labels containing
+    /// only `contacts`
+    pub(crate) fn with_contacts(
+        &self,
+        contacts: &[(VertexId, VertexId, TimeStep)],
+    ) -> TemporalGraph {
+        let mut graph = TemporalGraph::new();
+        for &v in &self.vertices {
+            graph.add_vertex(v);
+        }
+        graph.vertex_labels = self.vertex_labels.clone();
+        for &(u, v, t) in contacts {
+            graph.add_edge(u, v, t);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Star with centre 0 where every leaf talks to the centre at t=1,2,3.
+    /// A minimum spanner keeps only t=2 on one leaf and t=1,3 on the others.
+    fn triple_star() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        for leaf in 1..=3 {
+            g.add_edge(0, leaf, 1);
+            g.add_edge(0, leaf, 2);
+            g.add_edge(0, leaf, 3);
+        }
+        g
+    }
+
+    #[test]
+    fn test_spanner_of_disconnected_graph() {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 3);
+
+        assert!(g.minimum_temporal_spanner(true).is_none());
+        assert!(g.greedy_temporal_spanner(true).is_none());
+    }
+
+    #[test]
+    fn test_minimum_spanner_of_triple_star() {
+        let g = triple_star();
+        let spanner = g.minimum_temporal_spanner(true).unwrap();
+
+        assert!(spanner.is_temporally_connected(true));
+        assert_eq!(spanner.vertex_count(), g.vertex_count());
+        // At most one leaf can keep a single label, so 1 + 2 + 2 contacts
+        assert_eq!(spanner.contact_count(), 5);
+    }
+
+    #[test]
+    fn test_greedy_spanner_is_inclusion_minimal() {
+        let g = triple_star();
+        let spanner = g.greedy_temporal_spanner(true).unwrap();
+
+        assert!(spanner.is_temporally_connected(true));
+        for (u, v, t) in spanner.contacts() {
+            let mut smaller = spanner.clone_graph();
+            smaller.remove_edge_timestamp(u, v, t);
+            assert!(!smaller.is_temporally_connected(true));
+        }
+    }
+
+    #[test]
+    fn test_greedy_never_beats_exact() {
+        let mut g = triple_star();
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+
+        let exact = g.minimum_temporal_spanner(false).unwrap();
+        let greedy = g.greedy_temporal_spanner(false).unwrap();
+        assert!(exact.contact_count() <= greedy.contact_count());
+    }
+
+    #[test]
+    fn test_spanners_keep_vertex_labels() {
+        let mut g = triple_star();
+        g.vertex_labels.insert(0, "hub".to_string());
+
+        let exact = g.minimum_temporal_spanner(true).unwrap();
+        let greedy = g.greedy_temporal_spanner(true).unwrap();
+        assert_eq!(exact.vertex_labels, g.vertex_labels);
+        assert_eq!(greedy.vertex_labels, g.vertex_labels);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::{TemporalGraph, TimeStep, VertexId};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Kind of an arc in a `TimeExpandedGraph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeExpandedArcKind {
+    /// Staying at a vertex until its next layer.
+    Waiting,
+
+    /// Traversing contact `(from, to, time)` in the given direction.
+    Contact {
+        from: VertexId,
+        to: VertexId,
+        time: TimeStep,
+    },
+}
+
+/// Directed arc between two nodes of a `TimeExpandedGraph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeExpandedArc {
+    pub tail: usize,
+    pub head: usize,
+    pub kind: TimeExpandedArcKind,
+}
+
+/// Static directed graph unrolling a temporal graph over time.
+///
+/// Node `(v, t)` stands for being at vertex `v` at time `t`, ready to take
+/// a contact at `t`. Waiting arcs link consecutive nodes of the same vertex,
+/// and each contact gives one arc per direction. In non-strict mode a
+/// contact at `t` links the two endpoints within layer `t`, so several
+/// contacts at the same time can be chained; in strict mode it leads to
+/// layer `t + 1`. Journeys of the temporal graph are then exactly the paths
+/// of this digraph, up to waiting arcs.
+///
+/// The uncompressed graph has a node for every vertex on every layer. The
+/// compressed graph only keeps the nodes of each vertex at times where it
+/// takes part in a contact (or arrives from one, in strict mode), which
+/// preserves paths while dropping most waiting arcs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeExpandedGraph {
+    /// Vertices of the temporal graph, sorted.
+    pub vertices: Vec<VertexId>,
+    /// `(vertex, time)` of every node, sorted by time then vertex.
+    pub nodes: Vec<(VertexId, TimeStep)>,
+    /// All arcs, waiting arcs first.
+    pub arcs: Vec<TimeExpandedArc>,
+    /// Whether contacts lead to the next layer (strict) or the same one.
+    pub strict: bool,
+    /// Whether nodes without contacts were dropped.
+    pub compressed: bool,
+    index: HashMap<(VertexId, TimeStep), usize>,
+    outgoing: Vec<Vec<usize>>,
+}
+
+impl TimeExpandedGraph {
+    /// Unroll `graph` over time
+    ///
+    /// # Parameters
+    /// - `strict`: strictly increasing vs. non-decreasing timestamps.
+    /// - `compressed`: keep only the nodes where a vertex has contacts.
+    pub fn new(graph: &TemporalGraph, strict: bool, compressed: bool) -> Self {
+        let contacts = graph.contacts();
+        let arrival = |t: TimeStep| if strict { t.saturating_add(1) } else { t };
+
+        let mut timelines: HashMap<VertexId, BTreeSet<TimeStep>> = HashMap::new();
+        if compressed {
+            for &(u, v, t) in &contacts {
+                for w in [u, v] {
+                    let times = timelines.entry(w).or_default();
+                    times.insert(t);
+                    times.insert(arrival(t));
+                }
+            }
+        } else {
+            let layers: BTreeSet<TimeStep> = contacts
+                .iter()
+                .flat_map(|&(_, _, t)| [t, arrival(t)])
+                .collect();
+            for v in graph.vertices() {
+                timelines.insert(v, layers.clone());
+            }
+        }
+
+        let mut nodes: Vec<(VertexId, TimeStep)> = timelines
+            .iter()
+            .flat_map(|(&v, times)| times.iter().map(move |&t| (v, t)))
+            .collect();
+        nodes.sort_unstable_by_key(|&(v, t)| (t, v));
+        let index: HashMap<(VertexId, TimeStep), usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, i))
+            .collect();
+
+        let mut arcs = Vec::new();
+        let mut vertices: Vec<&VertexId> = timelines.keys().collect();
+        vertices.sort_unstable();
+        for v in vertices {
+            let times = &timelines[v];
+            for (&t, &next) in times.iter().zip(times.iter().skip(1)) {
+                arcs.push(TimeExpandedArc {
+                    tail: index[&(*v, t)],
+                    head: index[&(*v, next)],
+                    kind: TimeExpandedArcKind::Waiting,
+                });
+            }
+        }
+        for &(u, v, t) in &contacts {
+            for (from, to) in [(u, v), (v, u)] {
+                arcs.push(TimeExpandedArc {
+                    tail: index[&(from, t)],
+                    head: index[&(to, arrival(t))],
+                    kind: TimeExpandedArcKind::Contact { from, to, time: t },
+                });
+            }
+        }
+
+        let mut outgoing = vec![Vec::new(); nodes.len()];
+        for (i, arc) in arcs.iter().enumerate() {
+            outgoing[arc.tail].push(i);
+        }
+
+        Self {
+            vertices: graph.vertices(),
+            nodes,
+            arcs,
+            strict,
+            compressed,
+            index,
+            outgoing,
+        }
+    }
+
+    /// Number of nodes
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of arcs
+    pub fn arc_count(&self) -> usize {
+        self.arcs.len()
+    }
+
+    /// Index of node `(vertex, time)`, if it exists
+    pub fn node(&self, vertex: VertexId, time: TimeStep) -> Option<usize> {
+        self.index.get(&(vertex, time)).copied()
+    }
+
+    /// Nodes of `vertex`, in time order
+    pub fn nodes_of(&self, vertex: VertexId) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&i| self.nodes[i].0 == vertex)
+            .collect()
+    }
+
+    /// Indices of the arcs leaving `node`
+    pub fn outgoing(&self, node: usize) -> &[usize] {
+        &self.outgoing[node]
+    }
+
+    /// Original contact behind arc `arc`, as `(from, to, time)`, or `None`
+    /// for waiting arcs
+    pub fn contact_of(&self, arc: usize) -> Option<(VertexId, VertexId, TimeStep)> {
+        match self.arcs[arc].kind {
+            TimeExpandedArcKind::Waiting => None,
+            TimeExpandedArcKind::Contact { from, to, time } => Some((from, to, time)),
+        }
+    }
+
+    /// The two arcs (one per direction) of contact `(u, v, time)`
+    pub fn arcs_of_contact(&self, u: VertexId, v: VertexId, time: TimeStep) -> Vec<usize> {
+        (0..self.arcs.len())
+            .filter(|&i| {
+                self.contact_of(i)
+                    .is_some_and(|(a, b, t)| t == time && ((a, b) == (u, v) || (a, b) == (v, u)))
+            })
+            .collect()
+    }
+
+    /// Vertices reachable from `source` along paths starting at its first
+    /// node, including `source` itself; agrees with
+    /// `TemporalGraph::reachable_from`
+    pub fn reachable_from(&self, source: VertexId) -> HashSet<VertexId> {
+        let mut reachable = HashSet::new();
+        if self.vertices.binary_search(&source).is_err() {
+            return reachable;
+        }
+        reachable.insert(source);
+
+        let Some(&start) = self.nodes_of(source).first() else {
+            return reachable;
+        };
+
+        let mut seen = vec![false; self.nodes.len()];
+        seen[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            reachable.insert(self.nodes[node].0);
+            for &arc in &self.outgoing[node] {
+                let head = self.arcs[arc].head;
+                if !seen[head] {
+                    seen[head] = true;
+                    queue.push_back(head);
+                }
+            }
+        }
+
+        reachable
+    }
+}
+
+impl TemporalGraph {
+    /// Build the time-expanded graph, see `TimeExpandedGraph::new`
+    pub fn time_expanded(&self, strict: bool, compressed: bool) -> TimeExpandedGraph {
+        TimeExpandedGraph::new(self, strict, compressed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> TemporalGraph {
+        let mut g = TemporalGraph::new();
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 3);
+        g.add_edge(0, 3, 2);
+        g.add_vertex(4);
+        g
+    }
+
+    #[test]
+    fn test_node_and_arc_counts() {
+        let g = sample();
+
+        // Layers {1, 2, 3}, five vertices
+        let full = g.time_expanded(false, false);
+        assert_eq!(full.node_count(), 15);
+        assert_eq!(full.arc_count(), 5 * 2 + 2 * 4);
+
+        // 0: {1, 2}, 1: {1}, 2: {1, 3}, 3: {2, 3}
+        let compressed = g.time_expanded(false, true);
+        assert_eq!(compressed.node_count(), 7);
+        assert_eq!(compressed.arc_count(), 3 + 2 * 4);
+        assert_eq!(compressed.node(1, 2), None);
+        assert!(compressed.nodes_of(4).is_empty());
+    }
+
+    #[test]
+    fn test_arcs_map_back_to_contacts() {
+        let g = sample();
+        let teg = g.time_expanded(true, true);
+
+        let arcs = teg.arcs_of_contact(1, 2, 1);
+        assert_eq!(arcs.len(), 2);
+        let arc = teg.arcs[arcs[0]];
+        assert_eq!(teg.nodes[arc.tail].1, 1);
+        assert_eq!(teg.nodes[arc.head].1, 2);
+
+        let contacts: BTreeSet<_> = (0..teg.arc_count())
+            .filter_map(|i| teg.contact_of(i))
+            .map(|(u, v, t)| (u.min(v), u.max(v), t))
+            .collect();
+        assert_eq!(contacts, g.contacts().into_iter().collect());
+    }
+
+    #[test]
+    fn test_reachability_matches_temporal_graph() {
+        let g = sample();
+
+        for strict in [true, false] {
+            for compressed in [true, false] {
+                let teg = g.time_expanded(strict, compressed);
+                for s in g.vertices() {
+                    let expected = g.reachable_from(s, strict);
+                    let actual = teg.reachable_from(s);
+                    assert_eq!(
+                        actual, expected,
+                        "strict={strict} compressed={compressed} s={s}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{TemporalGraph, TimeStep};
+use crate::{
+    MinimizationStateGraph, ReachabilityGraph, TemporalGraph, TimeExpandedArcKind,
+    TimeExpandedGraph, TimeStep,
+};
 use graphviz_rust::dot_generator::*;
 use graphviz_rust::dot_structures::*;
 use graphviz_rust::printer::{DotPrinter, PrinterContext};
@@ -7,6 +10,7 @@ use graphviz_rust::exec;
 use std::fs::File;
 use std::io::Error;
 use std::io::Write;
+use std::collections::BTreeMap;
 
 impl TemporalGraph {
     /// Generate DOT format showing all edges with timestamp labels
@@ -140,3 +144,238 @@ impl TemporalGraph {
         Ok(())
     }
 }
+
+impl ReachabilityGraph {
+    /// Generate DOT format for the reachability digraph.
+    ///
+    /// Reachable pairs are drawn as solid black arcs. Pairs missing from the
+    /// complete digraph are drawn as dashed red arcs, and vertices that fail
+    /// to reach every other vertex are filled in red.
+    pub fn to_dot_with_missing_arcs(&self) -> Graph {
+        let mut stmts = Vec::new();
+
+        stmts.push(stmt!(
+            node!("node"; attr!("shape", "circle"), attr!("style", "filled"))
+        ));
+
+        let missing = self.missing_arcs();
+        for vertex in &self.vertices {
+            let fill = if missing.iter().any(|(s, _)| s == vertex) {
+                "salmon"
+            } else {
+                "lightblue"
+            };
+            stmts.push(stmt!(node!(vertex.to_string(); attr!("fillcolor", fill))));
+        }
+
+        for (s, t) in &self.arcs {
+            stmts.push(stmt!(edge!(
+                node_id!(s.to_string()) => node_id!(t.to_string());
+                attr!("color", "black")
+            )));
+        }
+
+        for (s, t) in &missing {
+            stmts.push(stmt!(edge!(
+                node_id!(s.to_string()) => node_id!(t.to_string());
+                attr!("color", "red"),
+                attr!("style", "dashed"),
+                attr!("constraint", "false")
+            )));
+        }
+
+        Graph::DiGraph {
+            id: id!("reachability_graph"),
+            strict: true,
+            stmts,
+        }
+    }
+
+    /// Save the reachability digraph with missing arcs highlighted
+    pub fn save_with_missing_arcs(&self, filename: &str) -> std::io::Result<()> {
+        let dot_graph = self.to_dot_with_missing_arcs();
+        let dot_string = dot_graph.print(&mut PrinterContext::default());
+
+        let dot_filename = format!("{}.dot", filename);
+        let mut file = File::create(&dot_filename)?;
+        file.write_all(dot_string.as_bytes())?;
+
+        exec(
+            dot_graph,
+            &mut PrinterContext::default(),
+            vec![
+                CommandArg::Format(Format::Png),
+                CommandArg::Output(format!("{}.png", filename)),
+            ],
+        )
+        .map_err(Error::other)?;
+
+        println!("Saved reachability graph visualization to {}.png", filename);
+        Ok(())
+    }
+}
+
+impl TimeExpandedGraph {
+    /// Generate a layered DOT diagram of the time-expanded graph.
+    ///
+    /// Layers are laid out left to right, one rank per timestamp. Contact
+    /// arcs are solid and labelled with their time; waiting arcs are dashed
+    /// gray.
+    pub fn to_dot_layered(&self) -> Graph {
+        let mut stmts = Vec::new();
+
+        stmts.push(stmt!(attr!("rankdir", "LR")));
+        stmts.push(stmt!(node!("node"; attr!("shape", "circle"), attr!("style", "filled"), attr!("fillcolor", "lightblue"))));
+
+        let node_name = |node: usize| {
+            let (v, t) = self.nodes[node];
+            format!("{}@{}", v, t)
+        };
+
+        let mut layers: BTreeMap<TimeStep, Vec<usize>> = BTreeMap::new();
+        for (node, &(_, t)) in self.nodes.iter().enumerate() {
+            layers.entry(t).or_default().push(node);
+        }
+
+        for (t, nodes) in &layers {
+            let mut layer = vec![stmt!(attr!("rank", "same"))];
+            for &node in nodes {
+                let label = format!("{}\\nt={}", self.nodes[node].0, t);
+                layer.push(stmt!(Node::new(
+                    node_id!(esc node_name(node)),
+                    vec![attr!("label", esc label)]
+                )));
+            }
+            stmts.push(stmt!(Subgraph {
+                id: id!(format!("layer_{}", t.to_string().replace('-', "m"))),
+                stmts: layer,
+            }));
+        }
+
+        for arc in &self.arcs {
+            let tail = node_id!(esc node_name(arc.tail));
+            let head = node_id!(esc node_name(arc.head));
+            match arc.kind {
+                TimeExpandedArcKind::Waiting => stmts.push(stmt!(edge!(
+                    tail => head;
+                    attr!("color", "gray"),
+                    attr!("style", "dashed")
+                ))),
+                TimeExpandedArcKind::Contact { time, .. } => stmts.push(stmt!(edge!(
+                    tail => head;
+                    attr!("color", "black"),
+                    attr!("label", time)
+                ))),
+            }
+        }
+
+        Graph::DiGraph {
+            id: id!("time_expanded_graph"),
+            strict: false,
+            stmts,
+        }
+    }
+
+    /// Save the layered time-expanded diagram
+    pub fn save_layered(&self, filename: &str) -> std::io::Result<()> {
+        let dot_graph = self.to_dot_layered();
+        let dot_string = dot_graph.print(&mut PrinterContext::default());
+
+        let dot_filename = format!("{}.dot", filename);
+        let mut file = File::create(&dot_filename)?;
+        file.write_all(dot_string.as_bytes())?;
+
+        exec(
+            dot_graph,
+            &mut PrinterContext::default(),
+            vec![
+                CommandArg::Format(Format::Png),
+                CommandArg::Output(format!("{}.png", filename)),
+            ],
+        )
+        .map_err(Error::other)?;
+
+        println!(
+            "Saved time-expanded graph visualization to {}.png",
+            filename
+        );
+        Ok(())
+    }
+}
+
+impl MinimizationStateGraph {
+    /// Generate a DOT diagram of the states and transitions of a run.
+    ///
+    /// Each state is labelled with its edge labels and each transition with
+    /// the wrapped edge and moved label. States and transitions of the cycle
+    /// that ended the run are drawn in red.
+    pub fn to_dot(&self) -> Graph {
+        let mut stmts = Vec::new();
+
+        stmts.push(stmt!(node!("node"; attr!("shape", "box"), attr!("style", "filled"), attr!("fillcolor", "lightblue"))));
+
+        let in_cycle = |state: usize| self.cycle_entry.is_some_and(|entry| state >= entry);
+
+        for (i, state) in self.states.iter().enumerate() {
+            let mut label = format!("S{}", i);
+            for ((u, v), times) in state.edge_labels() {
+                let times: Vec<String> = times.iter().map(|t| t.to_string()).collect();
+                label.push_str(&format!("\\n{}-{}: {}", u, v, times.join(",")));
+            }
+            let fill = if in_cycle(i) {
+                "lightcoral"
+            } else {
+                "lightblue"
+            };
+            stmts.push(stmt!(Node::new(
+                node_id!(format!("s{}", i)),
+                vec![attr!("label", esc label), attr!("fillcolor", fill)]
+            )));
+        }
+
+        for step in &self.transitions {
+            let wrapped = step.operation.wrapped;
+            let label = format!(
+                "{}-{} t={} to {}-{}",
+                wrapped.from.0, wrapped.from.1, wrapped.time, wrapped.to.0, wrapped.to.1
+            );
+            let tail = node_id!(format!("s{}", step.from));
+            let head = node_id!(format!("s{}", step.to));
+            let color = if step.in_cycle { "red" } else { "black" };
+            stmts.push(stmt!(edge!(
+                tail => head;
+                attr!("color", color),
+                attr!("label", esc label)
+            )));
+        }
+
+        Graph::DiGraph {
+            id: id!("minimization_states"),
+            strict: false,
+            stmts,
+        }
+    }
+
+    /// Save the state diagram as DOT and PNG
+    pub fn save(&self, filename: &str) -> std::io::Result<()> {
+        let dot_graph = self.to_dot();
+        let dot_string = dot_graph.print(&mut PrinterContext::default());
+
+        let dot_filename = format!("{}.dot", filename);
+        let mut file = File::create(&dot_filename)?;
+        file.write_all(dot_string.as_bytes())?;
+
+        exec(
+            dot_graph,
+            &mut PrinterContext::default(),
+            vec![
+                CommandArg::Format(Format::Png),
+                CommandArg::Output(format!("{}.png", filename)),
+            ],
+        )
+        .map_err(Error::other)?;
+
+        println!("Saved minimization state graph to {}.png", filename);
+        Ok(())
+    }
+}